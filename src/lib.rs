@@ -74,9 +74,20 @@
 //! ```
 //!
 //! The resultant WASM binary will be located under `target/wasm32-wasip1/release`. This file can be selected and loaded into the voxel engine.
+//!
+//! ## Threading
+//!
+//! WASM plugins run on a single thread, and the engine does not support `std::thread` or blocking primitives like `std::sync::mpsc`. Crates which rely upon them will fail at runtime. To defer work until later in the frame, use the single-threaded `sync::EventQueue` instead.
+//!
+//! ## Event ordering
+//!
+//! The order in which different systems handle the same event is unspecified, and handlers cannot be assigned priorities. When per-frame work must happen in a particular order (such as reading input before movement, and movement before drawing), split it across the [`timing::on::PreFrame`], [`timing::on::Frame`], and [`timing::on::PostFrame`] events, which are always raised in that order.
 
 pub use voxel_engine_macros::include_assets;
-pub use voxel_engine_types::{asset, input, math, physics, player, timing, Client, Server};
+pub use voxel_engine_types::{
+    asset, input, math, physics, player, render, schema, sync, timing, world, Client, EngineError,
+    LogLevel, LogSpan, Logger, Metrics, Server,
+};
 
 /// Allows for drawing user interfaces with `egui`.
 #[cfg(feature = "egui")]
@@ -37,14 +37,52 @@ impl AssetEncoder for VoxelAssetEncoder {
                 data,
                 format: ImageFormat::Png,
             })),
-            "toml" | "txt" => Ok(Some(Asset::Text {
-                value: String::from_utf8(data).map_err(WassetError::from_serialize)?,
+            "glb" | "gltf" => Ok(Some(Asset::Mesh {
+                data,
+                format: MeshFormat::Gltf,
             })),
+            "bin" | "dat" => Ok(Some(Asset::Binary { data })),
+            "toml" | "txt" => Ok(Some(encode_text(data))),
             _ => Ok(None),
         }
     }
 }
 
+/// Decodes a text asset, removing any byte order mark. If the data is
+/// not valid UTF-8 or UTF-16 text, it is stored as a binary asset instead.
+fn encode_text(data: Vec<u8>) -> Asset {
+    let text = if let Some(rest) = data.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        std::str::from_utf8(rest).ok().map(str::to_owned)
+    } else if let Some(rest) = data.strip_prefix(&[0xFF, 0xFE]) {
+        decode_utf16(rest, u16::from_le_bytes)
+    } else if let Some(rest) = data.strip_prefix(&[0xFE, 0xFF]) {
+        decode_utf16(rest, u16::from_be_bytes)
+    } else {
+        std::str::from_utf8(&data)
+            .ok()
+            .filter(|x| !x.contains('\0'))
+            .map(str::to_owned)
+    };
+
+    match text {
+        Some(value) => Asset::Text { value },
+        None => Asset::Binary { data },
+    }
+}
+
+/// Decodes UTF-16 text, using the provided function to convert pairs of bytes into code units.
+fn decode_utf16(data: &[u8], code_unit: fn([u8; 2]) -> u16) -> Option<String> {
+    if data.len() % 2 != 0 {
+        return None;
+    }
+
+    let units = data
+        .chunks_exact(2)
+        .map(|x| code_unit([x[0], x[1]]))
+        .collect::<Vec<_>>();
+    String::from_utf16(&units).ok()
+}
+
 /// Patch `wings` dependency without including `wings_host`.
 #[no_mangle]
 extern "C" fn __wings_invoke_proxy_function() {}
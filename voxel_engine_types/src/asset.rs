@@ -1,3 +1,4 @@
+use crate::math::*;
 use crate::*;
 use wasset::*;
 
@@ -5,6 +6,10 @@ use wasset::*;
 pub type AssetId = WassetId;
 
 /// Holds the raw data for an embedded game asset.
+///
+/// Assets are serialized with their variant index, so new variants must only be appended
+/// after the existing ones. Reordering or inserting variants would change the meaning
+/// of assets exchanged with already-built mods and hosts.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum Asset {
@@ -20,6 +25,18 @@ pub enum Asset {
         /// The text within this document.
         value: String,
     },
+    /// A 3D model consisting of a format and data.
+    Mesh {
+        /// The raw bytes of the mesh.
+        data: Vec<u8>,
+        /// The format of the mesh.
+        format: MeshFormat,
+    },
+    /// Arbitrary binary data.
+    Binary {
+        /// The raw bytes of the file.
+        data: Vec<u8>,
+    },
 }
 
 /// Describes the format of an image.
@@ -32,35 +49,335 @@ pub enum ImageFormat {
     Png,
 }
 
+/// Describes the format of a mesh.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum MeshFormat {
+    /// The mesh is encoded as a glTF 2.0 document, in either
+    /// the text (`.gltf`) or binary (`.glb`) container.
+    Gltf,
+}
+
 /// Allows for loading and using embedded assets.
 #[system_trait(host)]
 pub trait AssetManager: 'static {
+    /// Gets a stable hash of the asset's contents, or `None` if the asset does not exist.
+    /// The hash changes whenever the asset's data changes, so it may be used to
+    /// invalidate caches of data derived from the asset.
+    fn asset_hash(&self, id: AssetId) -> Option<u64>;
+
+    /// Gets the source path of an asset, relative to its asset directory, or `None` if the
+    /// asset does not exist. Unlike the numeric ID, which may change whenever the mod is
+    /// rebuilt, the path is stable and may be stored in save data or configuration files.
+    fn asset_path(&self, id: AssetId) -> Option<String>;
+
+    /// Gets the total size, in bytes, of all asset data currently held in the host's cache.
+    fn cache_size_bytes(&self) -> u64;
+
+    /// Removes an asset's decoded data and textures from the host's cache. This is always
+    /// safe: the asset is transparently reloaded the next time that it is accessed.
+    fn evict(&self, id: AssetId);
+
+    /// Gets the asset with the given source path, as returned by [`Self::asset_path`],
+    /// or `None` if no such asset exists.
+    fn id_from_path(&self, path: &str) -> Option<AssetId>;
+
+    /// Adds an asset which was generated at runtime, and returns an ID that may be used
+    /// with all other asset functions for the rest of the session. Runtime assets have no
+    /// source path, so they are never returned by [`Self::id_from_path`].
+    fn register_runtime_asset(&self, asset: Asset) -> AssetId;
+
+    /// Sets the maximum size, in bytes, of the host's asset cache. When the cache grows
+    /// beyond this limit, the least recently used assets are evicted.
+    /// Pass [`u64::MAX`] to remove the limit.
+    fn set_cache_limit(&self, bytes: u64);
+
     /// Gets the raw data for an asset.
-    fn try_get_raw(&self, id: AssetId) -> Result<Asset, EngineError>;
+    fn try_get_raw(&self, id: AssetId) -> Result<Asset, AssetError>;
 
     /// Attempts to get a handle that be used to draw `Image` assets as `egui` images.
-    fn try_get_ui_texture(&self, id: AssetId) -> Result<UiTextureIndex, EngineError>;
+    fn try_get_ui_texture(&self, id: AssetId) -> Result<UiTextureIndex, AssetError>;
+
+    /// Attempts to get a handle that can be used to draw a rectangular region of an `Image`
+    /// asset as an `egui` image. The region is given in normalized texture coordinates,
+    /// where `(0, 0)` is the top-left corner and `(1, 1)` is the bottom-right corner.
+    /// The returned width and height are those of the region.
+    fn try_get_ui_texture_region(
+        &self,
+        id: AssetId,
+        uv_min: Vec2,
+        uv_max: Vec2,
+    ) -> Result<UiTextureIndex, AssetError>;
+
+    /// Replaces the data of an asset that was added with [`Self::register_runtime_asset`].
+    /// Any cached data and textures derived from the asset are refreshed, and its
+    /// [`Self::asset_hash`] changes. Embedded assets cannot be updated.
+    fn update_runtime_asset(&self, id: AssetId, asset: Asset) -> Result<(), AssetError>;
+
+    /// Begins loading an asset in the background, returning a handle to the load.
+    #[doc(hidden)]
+    fn begin_load(&self, id: AssetId) -> u64;
+
+    /// Opens a stream which reads an asset's raw bytes incrementally, returning a handle to it.
+    #[doc(hidden)]
+    fn begin_stream(&self, id: AssetId) -> Result<u64, AssetError>;
+
+    /// Checks whether a background load has finished.
+    #[doc(hidden)]
+    #[global(global_poll_load)]
+    fn poll_load(&self, load: u64) -> Option<Result<Asset, AssetError>>;
+
+    /// Reads between `1` and `max` bytes from a stream, or returns `None` at the end of the
+    /// stream. `max` is never zero.
+    #[doc(hidden)]
+    #[global(global_read_stream)]
+    fn read_stream(&self, stream: u64, max: u64) -> Option<Vec<u8>>;
+
+    /// Releases the resources associated with a background load.
+    #[doc(hidden)]
+    #[global(global_release_load)]
+    fn release_load(&self, load: u64);
+
+    /// Releases the resources associated with a stream.
+    #[doc(hidden)]
+    #[global(global_release_stream)]
+    fn release_stream(&self, stream: u64);
 }
 
 impl dyn AssetManager {
+    /// Gets the extension of the file from which an asset was embedded, without the leading
+    /// dot. This distinguishes between assets of the same kind, such as `toml` and `txt` files
+    /// which are both [`Asset::Text`]. Returns `None` if the asset does not exist, was added at
+    /// runtime, or its file had no extension.
+    pub fn asset_extension(&self, id: AssetId) -> Option<String> {
+        let path = self.asset_path(id)?;
+        let name = path.rsplit('/').next()?;
+        name.rsplit_once('.')
+            .filter(|(stem, _)| !stem.is_empty())
+            .map(|(_, extension)| extension.to_owned())
+    }
+
     /// Deserializes the given TOML-table asset as `T`. Panics if the conversion fails.
     #[cfg(feature = "toml")]
     pub fn get_from_toml<T: 'static + serde::de::DeserializeOwned>(&self, id: AssetId) -> T {
-        match self.try_get_raw(id).expect("Failed to get asset.") {
-            Asset::Text { value } => {
-                toml::from_str(&value).expect("Failed to deserialize TOML map.")
-            }
-            x => panic!("Expected text asset; got {x:?}"),
+        self.try_get_from_toml(id)
+            .expect("Failed to deserialize TOML asset.")
+    }
+
+    /// Attempts to deserialize the given TOML-table asset as `T`.
+    #[cfg(feature = "toml")]
+    pub fn try_get_from_toml<T: 'static + serde::de::DeserializeOwned>(
+        &self,
+        id: AssetId,
+    ) -> Result<T, AssetError> {
+        match self.try_get_raw(id)? {
+            Asset::Text { value } => toml::from_str(&value).map_err(|x| AssetError::DecodeFailed {
+                message: x.to_string(),
+            }),
+            _ => Err(AssetError::WrongKind),
         }
     }
 
+    /// Begins loading an asset without blocking the current frame.
+    /// The returned future should be polled until the asset is available.
+    pub fn load_async(&self, id: AssetId) -> AssetFuture {
+        AssetFuture(self.begin_load(id))
+    }
+
+    /// Shorthand for `try_open_stream(id).unwrap()`.
+    pub fn open_stream(&self, id: AssetId) -> AssetStream {
+        self.try_open_stream(id)
+            .expect("Failed to open asset stream.")
+    }
+
+    /// Opens a stream which reads the raw bytes of an asset incrementally, rather than copying
+    /// the whole asset at once. This is useful for parsing large embedded data files. For text
+    /// assets, the stream yields the text encoded as UTF-8.
+    pub fn try_open_stream(&self, id: AssetId) -> Result<AssetStream, AssetError> {
+        self.begin_stream(id).map(AssetStream)
+    }
+
+    /// Gets the raw bytes of an asset. For text assets, this is the text encoded as UTF-8.
+    pub fn try_get_bytes(&self, id: AssetId) -> Result<Vec<u8>, AssetError> {
+        Ok(match self.try_get_raw(id)? {
+            Asset::Binary { data } | Asset::Image { data, .. } | Asset::Mesh { data, .. } => data,
+            Asset::Text { value } => value.into_bytes(),
+        })
+    }
+
+    /// Shorthand for `try_get_text_resolved(id).unwrap()`.
+    pub fn get_text_resolved(&self, id: AssetId) -> String {
+        self.try_get_text_resolved(id)
+            .expect("Failed to resolve text asset.")
+    }
+
+    /// Gets the contents of a text asset, replacing each line of the form
+    /// `//!include "path"` with the resolved contents of the named text asset.
+    /// Paths are relative to the directory of the asset containing the directive, or to the
+    /// asset root for assets added with [`AssetManager::register_runtime_asset`].
+    /// Returns [`AssetError::IncludeCycle`] if an asset directly or indirectly includes itself,
+    /// or [`AssetError::IncludeNotFound`] if an included asset does not exist.
+    pub fn try_get_text_resolved(&self, id: AssetId) -> Result<String, AssetError> {
+        self.resolve_includes(id, &mut Vec::new())
+    }
+
     /// Shorthand for `try_get_ui_texture(id).unwrap()`.
     pub fn get_ui_texture(&self, id: AssetId) -> UiTextureIndex {
         self.try_get_ui_texture(id)
             .expect("Failed to load image asset.")
     }
+
+    /// Shorthand for `try_get_ui_texture_region(id, uv_min, uv_max).unwrap()`.
+    pub fn get_ui_texture_region(&self, id: AssetId, uv_min: Vec2, uv_max: Vec2) -> UiTextureIndex {
+        self.try_get_ui_texture_region(id, uv_min, uv_max)
+            .expect("Failed to load image asset.")
+    }
+
+    /// Expands the include directives within a text asset. The paths of all
+    /// assets currently being expanded are held in `stack`, to detect cycles.
+    /// Runtime assets have no path, but they also cannot be included, so
+    /// they never take part in a cycle.
+    fn resolve_includes(&self, id: AssetId, stack: &mut Vec<String>) -> Result<String, AssetError> {
+        let path = self.asset_path(id);
+        if let Some(path) = path.as_ref().filter(|x| stack.contains(x)) {
+            return Err(AssetError::IncludeCycle { path: path.clone() });
+        }
+
+        let Asset::Text { value } = self.try_get_raw(id)? else {
+            return Err(AssetError::WrongKind);
+        };
+
+        let directory = path
+            .as_deref()
+            .and_then(|x| x.rsplit_once('/'))
+            .map(|(x, _)| x)
+            .unwrap_or_default()
+            .to_string();
+        stack.extend(path.clone());
+
+        let mut result = String::with_capacity(value.len());
+        for line in value.split_inclusive('\n') {
+            if let Some(include) = parse_include(line) {
+                let target = join_asset_path(&directory, include);
+                let Some(id) = self.id_from_path(&target) else {
+                    return Err(AssetError::IncludeNotFound { path, target });
+                };
+                result.push_str(&self.resolve_includes(id, stack)?);
+
+                if line.ends_with('\n') && !result.ends_with('\n') {
+                    result.push('\n');
+                }
+            } else {
+                result.push_str(line);
+            }
+        }
+
+        if path.is_some() {
+            stack.pop();
+        }
+        Ok(result)
+    }
+}
+
+/// An asset that is being loaded in the background. The load is cancelled
+/// if this value is dropped before it completes.
+#[derive(Debug)]
+pub struct AssetFuture(u64);
+
+impl AssetFuture {
+    /// Checks whether the asset has finished loading. Returns `None` while the
+    /// load is still in progress. This never blocks, so it may be called once per frame.
+    pub fn poll(&self) -> Option<Result<Asset, AssetError>> {
+        global_poll_load(self.0)
+    }
+}
+
+impl Drop for AssetFuture {
+    fn drop(&mut self) {
+        global_release_load(self.0);
+    }
+}
+
+/// Reads the bytes of an asset incrementally. The stream is closed when this value is dropped.
+#[derive(Debug)]
+pub struct AssetStream(u64);
+
+impl AssetStream {
+    /// Reads the next chunk of the asset, containing between `1` and `max` bytes. A chunk may
+    /// be shorter than `max` even if more data remains, so only a return value of `None`
+    /// signals the end of the stream; an empty chunk is never returned. Chunk boundaries
+    /// are arbitrary, so a chunk of a text asset may end partway through a UTF-8 character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is zero.
+    pub fn read_chunk(&mut self, max: usize) -> Option<Vec<u8>> {
+        assert!(max > 0, "Chunk size must be greater than zero.");
+        global_read_stream(self.0, max as u64)
+    }
+}
+
+impl Drop for AssetStream {
+    fn drop(&mut self) {
+        global_release_stream(self.0);
+    }
+}
+
+/// Describes why an asset could not be loaded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum AssetError {
+    /// The asset data could not be decoded.
+    DecodeFailed {
+        /// A description of the decoding failure.
+        message: String,
+    },
+    /// A text asset includes itself, either directly or through other assets.
+    IncludeCycle {
+        /// The path of the asset that was included recursively.
+        path: String,
+    },
+    /// A text asset includes another asset which does not exist.
+    IncludeNotFound {
+        /// The path of the asset containing the include directive,
+        /// or `None` if it was added with [`AssetManager::register_runtime_asset`].
+        path: Option<String>,
+        /// The path of the asset that could not be found.
+        target: String,
+    },
+    /// No asset with the given ID exists.
+    NotFound,
+    /// The asset was embedded in a mod, so it cannot be modified.
+    ReadOnly,
+    /// The asset exists, but is not of the requested kind.
+    WrongKind,
+}
+
+impl std::fmt::Display for AssetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetError::DecodeFailed { message } => {
+                f.write_fmt(format_args!("Failed to decode asset: {message}"))
+            }
+            AssetError::IncludeCycle { path } => {
+                f.write_fmt(format_args!("Asset {path} includes itself"))
+            }
+            AssetError::IncludeNotFound {
+                path: Some(path),
+                target,
+            } => f.write_fmt(format_args!("Asset {path} includes missing asset {target}")),
+            AssetError::IncludeNotFound { path: None, target } => f.write_fmt(format_args!(
+                "Runtime asset includes missing asset {target}"
+            )),
+            AssetError::NotFound => f.write_str("Asset not found"),
+            AssetError::ReadOnly => f.write_str("Embedded assets cannot be modified"),
+            AssetError::WrongKind => f.write_str("Asset was not of the requested kind"),
+        }
+    }
 }
 
+impl std::error::Error for AssetError {}
+
 /// The allocated index of a UI texture. Only valid for a single frame;
 /// `AssetManager::get_ui_texture` should be called to get a new index
 /// for every usage.
@@ -86,3 +403,197 @@ impl<'a> From<UiTextureIndex> for egui_wings::egui::ImageSource<'a> {
         })
     }
 }
+
+/// Gets the path named by an include directive, if the line contains one.
+fn parse_include(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix("//!include")?
+        .trim()
+        .strip_prefix('"')?
+        .strip_suffix('"')
+}
+
+/// Joins a relative path onto an asset directory, resolving any `.` and `..` segments.
+fn join_asset_path(directory: &str, relative: &str) -> String {
+    let mut segments = Vec::new();
+    for segment in directory.split('/').chain(relative.split('/')) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::*;
+
+    /// Holds assets in memory, along with their paths if they were embedded.
+    #[derive(Default)]
+    struct MemoryAssets(RefCell<Vec<(Option<String>, Asset)>>);
+
+    impl MemoryAssets {
+        /// Adds an embedded text asset with the given path.
+        fn embed(&self, path: &str, value: &str) -> AssetId {
+            let mut assets = self.0.borrow_mut();
+            assets.push((
+                Some(path.to_string()),
+                Asset::Text {
+                    value: value.to_string(),
+                },
+            ));
+            AssetId::from(assets.len() as u128 - 1)
+        }
+
+        /// Gets the entry for the asset with the given ID.
+        fn entry(&self, id: AssetId) -> Option<(Option<String>, Asset)> {
+            let index = usize::try_from(u128::from(id)).ok()?;
+            self.0.borrow().get(index).cloned()
+        }
+    }
+
+    impl AssetManager for MemoryAssets {
+        fn asset_hash(&self, _: AssetId) -> Option<u64> {
+            unimplemented!()
+        }
+
+        fn asset_path(&self, id: AssetId) -> Option<String> {
+            self.entry(id)?.0
+        }
+
+        fn cache_size_bytes(&self) -> u64 {
+            unimplemented!()
+        }
+
+        fn evict(&self, _: AssetId) {
+            unimplemented!()
+        }
+
+        fn id_from_path(&self, path: &str) -> Option<AssetId> {
+            self.0
+                .borrow()
+                .iter()
+                .position(|x| x.0.as_deref() == Some(path))
+                .map(|x| AssetId::from(x as u128))
+        }
+
+        fn register_runtime_asset(&self, asset: Asset) -> AssetId {
+            let mut assets = self.0.borrow_mut();
+            assets.push((None, asset));
+            AssetId::from(assets.len() as u128 - 1)
+        }
+
+        fn set_cache_limit(&self, _: u64) {
+            unimplemented!()
+        }
+
+        fn try_get_raw(&self, id: AssetId) -> Result<Asset, AssetError> {
+            self.entry(id).map(|x| x.1).ok_or(AssetError::NotFound)
+        }
+
+        fn try_get_ui_texture(&self, _: AssetId) -> Result<UiTextureIndex, AssetError> {
+            unimplemented!()
+        }
+
+        fn try_get_ui_texture_region(
+            &self,
+            _: AssetId,
+            _: Vec2,
+            _: Vec2,
+        ) -> Result<UiTextureIndex, AssetError> {
+            unimplemented!()
+        }
+
+        fn update_runtime_asset(&self, _: AssetId, _: Asset) -> Result<(), AssetError> {
+            unimplemented!()
+        }
+
+        fn begin_load(&self, _: AssetId) -> u64 {
+            unimplemented!()
+        }
+
+        fn begin_stream(&self, _: AssetId) -> Result<u64, AssetError> {
+            unimplemented!()
+        }
+
+        fn poll_load(&self, _: u64) -> Option<Result<Asset, AssetError>> {
+            unimplemented!()
+        }
+
+        fn read_stream(&self, _: u64, _: u64) -> Option<Vec<u8>> {
+            unimplemented!()
+        }
+
+        fn release_load(&self, _: u64) {
+            unimplemented!()
+        }
+
+        fn release_stream(&self, _: u64) {
+            unimplemented!()
+        }
+    }
+
+    /// Registers a runtime text asset with the given contents.
+    fn runtime_text(assets: &dyn AssetManager, value: &str) -> AssetId {
+        assets.register_runtime_asset(Asset::Text {
+            value: value.to_string(),
+        })
+    }
+
+    #[test]
+    fn runtime_text_resolves() {
+        let assets = MemoryAssets::default();
+        let manager: &dyn AssetManager = &assets;
+
+        let id = runtime_text(manager, "plain text\n");
+        assert_eq!(
+            manager.try_get_text_resolved(id).as_deref(),
+            Ok("plain text\n")
+        );
+    }
+
+    #[test]
+    fn runtime_text_includes_from_root() {
+        let assets = MemoryAssets::default();
+        assets.embed("shaders/common.wgsl", "fn common() {}\n");
+        let manager: &dyn AssetManager = &assets;
+
+        let id = runtime_text(
+            manager,
+            "//!include \"shaders/common.wgsl\"\nfn main() {}\n",
+        );
+        assert_eq!(
+            manager.try_get_text_resolved(id).as_deref(),
+            Ok("fn common() {}\nfn main() {}\n")
+        );
+
+        let missing = runtime_text(manager, "//!include \"missing.wgsl\"\n");
+        assert_eq!(
+            manager.try_get_text_resolved(missing),
+            Err(AssetError::IncludeNotFound {
+                path: None,
+                target: "missing.wgsl".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn embedded_includes_report_missing_targets() {
+        let assets = MemoryAssets::default();
+        let id = assets.embed("shaders/main.wgsl", "//!include \"../lib/missing.wgsl\"\n");
+        let manager: &dyn AssetManager = &assets;
+
+        assert_eq!(
+            manager.try_get_text_resolved(id),
+            Err(AssetError::IncludeNotFound {
+                path: Some("shaders/main.wgsl".to_string()),
+                target: "lib/missing.wgsl".to_string(),
+            })
+        );
+    }
+}
@@ -2,15 +2,45 @@ use crate::math::*;
 use private::*;
 use serde::de::*;
 use serde::*;
+use std::collections::*;
 use std::hash::*;
 use std::marker::*;
+use std::time::*;
 use wings::*;
 
 /// Allows for reading from the user's input devices. Only available on the [`Client`](crate::Client).
 #[system_trait(host)]
 pub trait Input: 'static {
+    /// Gets the kind of device that most recently produced input.
+    fn active_device(&self) -> DeviceKind;
+
+    /// Marks a raw input as handled for the remainder of this frame. Afterward, [`Self::get_raw`]
+    /// and any actions bound to the input will report a neutral value, so that other systems
+    /// do not handle the input again. Pointer inputs handled by `egui` are consumed automatically.
+    fn consume(&mut self, raw_input: RawInput);
+
+    /// Registers an ordered sequence of digital actions, such as a cheat code or special move.
+    /// The sequence is triggered when every action is pressed in order, with the whole sequence
+    /// completed within `window`. Progress is reset if the window elapses or if any other
+    /// action in the sequence is pressed out of order.
+    fn define_sequence(&mut self, actions: &[ActionId<Digital>], window: Duration) -> SequenceId;
+
+    /// Registers an on-screen joystick which is controlled by touching the given region.
+    /// When a touch begins within the region, the stick's center is placed beneath it, and
+    /// dragging the touch moves the stick. The stick's radius is half of the region's smaller
+    /// side. Read the stick with [`Self::virtual_stick`].
+    fn define_virtual_stick(&mut self, region: ScreenRect) -> VirtualStickId;
+
+    /// Gets the IDs of all gamepads that are currently connected.
+    fn gamepads(&self) -> Vec<GamepadId>;
+
+    /// Gets the values of every axis and button on the given gamepad at once,
+    /// without considering whether any actions are registered with them. If the
+    /// gamepad is not connected, all values are zero.
+    fn gamepad_state(&self, gamepad: GamepadId) -> GamepadState;
+
     /// Gets the value of a raw input, without considering whether any actions
-    /// are registered with it.
+    /// are registered with it. Returns `0.0` if the input was consumed this frame.
     fn get_raw(&self, raw_input: RawInput) -> f32;
 
     /// Gets the mouse cursor's movement for this frame. This value
@@ -20,20 +50,80 @@ pub trait Input: 'static {
     /// Gets the normalized direction that the user's mouse is pointing
     /// in 3D space. When the pointer is locked, this is always relative
     /// to the screen center. When the pointer is over a UI element (or
-    /// something other than the game) this returns `None`.
+    /// something other than the game) this returns `None`; use
+    /// [`Self::pointer_over_ui`] and [`Self::pointer_in_window`] to distinguish these cases.
     fn pointer_direction(&self) -> Option<Vec3A>;
 
-    /// Returns whether the pointer is currently locked to the center of the screen.
+    /// Returns whether the mouse cursor is currently within the game window.
+    fn pointer_in_window(&self) -> bool;
+
+    /// Returns whether the pointer is currently locked to the center of the screen. This
+    /// reflects the actual state of the pointer, which may differ from the most recent
+    /// call to [`Self::set_pointer_locked`] if the platform denied or released the lock.
     fn pointer_locked(&self) -> bool;
 
-    /// Sets whether the mouse cursor will be invisible and locked to the center of the screen.
-    /// The user's mouse movements can then be read with [`Self::pointer_delta`].
+    /// Returns whether the mouse cursor is currently over a UI element,
+    /// such as an `egui` panel. World interactions should typically be
+    /// ignored while this is `true`.
+    fn pointer_over_ui(&self) -> bool;
+
+    /// Returns whether the given sequence was completed this frame. This
+    /// is `true` for only the single frame on which the final press occurs.
+    fn sequence_triggered(&self, id: SequenceId) -> bool;
+
+    /// Sets the input buffer window for a digital action. If the action becomes pressed, it
+    /// will continue to report [`DigitalResult::pressed`] on subsequent frames until `window`
+    /// has elapsed, so that presses made slightly too early still register. A zero window,
+    /// which is the default, disables buffering.
+    fn set_buffer_window(&mut self, id: ActionId<Digital>, window: Duration);
+
+    /// Sets the shape of the mouse cursor while it is over the world. `egui` may
+    /// override this while the cursor is over a UI element.
+    fn set_cursor_icon(&mut self, icon: CursorIcon);
+
+    /// Requests that the mouse cursor be invisible and locked to the center of the screen.
+    /// The user's mouse movements can then be read with [`Self::pointer_delta`]. The platform
+    /// may deny or later release the lock, such as when the user presses Escape, so the lock
+    /// takes effect only once [`on::PointerLockChanged`] is raised.
     fn set_pointer_locked(&mut self, locked: bool);
 
+    /// Sets whether the first mouse input after the game window regains focus is ignored,
+    /// so that the click which refocuses the window does not trigger any actions.
+    /// This is enabled by default.
+    fn set_swallow_refocus_click(&mut self, swallow: bool);
+
+    /// Sets the resistance effect applied to an adaptive trigger on the given gamepad.
+    /// Does nothing if the gamepad does not support trigger effects.
+    fn set_trigger_effect(
+        &mut self,
+        gamepad: GamepadId,
+        trigger: GamepadTrigger,
+        effect: TriggerEffect,
+    );
+
     /// Gets the number of ticks that the mouse wheel has scrolled
     /// this frame. This value is given in device units.
     fn scroll_delta(&self) -> IVec2;
 
+    /// Returns whether the first mouse input after the game window regains focus is ignored.
+    fn swallow_refocus_click(&self) -> bool;
+
+    /// Takes a frozen copy of the current input state. All reads from the
+    /// snapshot will observe the same values, even if the host updates
+    /// its input state later in the frame.
+    fn snapshot(&self) -> InputSnapshot;
+
+    /// Gets every point at which the user is currently touching the screen. Touches which
+    /// ended during this frame are included with a phase of [`TouchPhase::Ended`] or
+    /// [`TouchPhase::Cancelled`]. For compatibility, a single touch also acts as
+    /// [`MouseButton::Left`].
+    fn touches(&self) -> Vec<Touch>;
+
+    /// Gets the position of an on-screen joystick registered with [`Self::define_virtual_stick`].
+    /// The value has a length of at most `1.0`, with `+y` pointing toward the top of the
+    /// screen as with gamepad sticks. It is zero while the stick is not being touched.
+    fn virtual_stick(&self, id: VirtualStickId) -> Vec2;
+
     /// Gets a handle referencing the given analog action,
     /// which may take on a continuous range of values.
     /// The action is created if it does not exist.
@@ -49,9 +139,17 @@ pub trait Input: 'static {
     #[doc(hidden)]
     fn get_analog(&self, id: ActionId<Analog>) -> f32;
 
+    /// Gets the current values of many analog actions at once.
+    #[doc(hidden)]
+    fn get_analogs(&self, ids: &[ActionId<Analog>]) -> Vec<f32>;
+
     /// Gets the current value of the provided digital action.
     #[doc(hidden)]
     fn get_digital(&self, id: ActionId<Digital>) -> DigitalResult;
+
+    /// Gets the current values of many digital actions at once.
+    #[doc(hidden)]
+    fn get_digitals(&self, ids: &[ActionId<Digital>]) -> Vec<DigitalResult>;
 }
 
 impl dyn Input {
@@ -64,6 +162,30 @@ impl dyn Input {
     pub fn get<I: InputKind>(&self, id: ActionId<I>) -> I::Result {
         I::get(self, id)
     }
+
+    /// Gets the current values of many actions in a single call, in the same order as `ids`.
+    /// This is cheaper than calling [`Self::get`] for each action individually.
+    pub fn get_many<I: InputKind>(&self, ids: &[ActionId<I>]) -> Vec<I::Result> {
+        I::get_many(self, ids)
+    }
+
+    /// Gets the position of a thumbstick as a 2D vector, with a radial deadzone
+    /// of [`GamepadStick::DEADZONE`] applied. The result has a length on the range `[0.0, 1.0]`.
+    pub fn stick(&self, stick: GamepadStick) -> Vec2 {
+        let (x, y) = stick.axes();
+        let value = vec2(
+            self.get_raw(RawInput::GamepadAxis(x)),
+            self.get_raw(RawInput::GamepadAxis(y)),
+        );
+
+        let length = value.length();
+        if length <= GamepadStick::DEADZONE {
+            Vec2::ZERO
+        } else {
+            let scaled = (length - GamepadStick::DEADZONE) / (1.0 - GamepadStick::DEADZONE);
+            value * (scaled.min(1.0) / length)
+        }
+    }
 }
 
 /// Inputs that return a continuous range of values.
@@ -89,6 +211,12 @@ pub trait InputKind: Sealed + Sized {
 
     /// Gets the state of the given action.
     fn get(input: &dyn Input, id: ActionId<Self>) -> Self::Result;
+
+    /// Gets the states of many actions at once.
+    fn get_many(input: &dyn Input, ids: &[ActionId<Self>]) -> Vec<Self::Result>;
+
+    /// Gets the state of the given action from a snapshot.
+    fn get_snapshot(snapshot: &InputSnapshot, id: ActionId<Self>) -> Self::Result;
 }
 
 impl InputKind for Analog {
@@ -103,6 +231,14 @@ impl InputKind for Analog {
     fn get(input: &dyn Input, id: ActionId<Self>) -> Self::Result {
         input.get_analog(id)
     }
+
+    fn get_many(input: &dyn Input, ids: &[ActionId<Self>]) -> Vec<Self::Result> {
+        input.get_analogs(ids)
+    }
+
+    fn get_snapshot(snapshot: &InputSnapshot, id: ActionId<Self>) -> Self::Result {
+        snapshot.analog.get(&id).copied().unwrap_or_default()
+    }
 }
 
 impl InputKind for Digital {
@@ -117,6 +253,46 @@ impl InputKind for Digital {
     fn get(input: &dyn Input, id: ActionId<Self>) -> Self::Result {
         input.get_digital(id)
     }
+
+    fn get_many(input: &dyn Input, ids: &[ActionId<Self>]) -> Vec<Self::Result> {
+        input.get_digitals(ids)
+    }
+
+    fn get_snapshot(snapshot: &InputSnapshot, id: ActionId<Self>) -> Self::Result {
+        snapshot.digital.get(&id).copied().unwrap_or_default()
+    }
+}
+
+/// A frozen copy of the user's input state, taken at a single point in time.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputSnapshot {
+    /// The values of all registered analog actions.
+    pub analog: HashMap<ActionId<Analog>, f32>,
+    /// The values of all registered digital actions.
+    pub digital: HashMap<ActionId<Digital>, DigitalResult>,
+    /// The mouse cursor's movement for the frame.
+    pub pointer_delta: Vec2,
+    /// The direction that the user's mouse was pointing in 3D space.
+    pub pointer_direction: Option<Vec3A>,
+    /// Whether the pointer was locked to the center of the screen.
+    pub pointer_locked: bool,
+    /// The values of all raw inputs. Inputs that are absent had a value of `0.0`.
+    pub raw: HashMap<RawInput, f32>,
+    /// The number of ticks that the mouse wheel scrolled during the frame.
+    pub scroll_delta: IVec2,
+}
+
+impl InputSnapshot {
+    /// Gets the value of the provided action at the time of this snapshot.
+    pub fn get<I: InputKind>(&self, id: ActionId<I>) -> I::Result {
+        I::get_snapshot(self, id)
+    }
+
+    /// Gets the value of a raw input at the time of this snapshot, without considering
+    /// whether any actions are registered with it.
+    pub fn get_raw(&self, raw_input: RawInput) -> f32 {
+        self.raw.get(&raw_input).copied().unwrap_or_default()
+    }
 }
 
 /// Identifies an action that has been bound for user input.
@@ -135,6 +311,22 @@ impl<I: InputKind> From<ActionId<I>> for u64 {
     }
 }
 
+/// Identifies a sequence of actions registered with [`Input::define_sequence`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct SequenceId(u64);
+
+impl From<u64> for SequenceId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SequenceId> for u64 {
+    fn from(value: SequenceId) -> Self {
+        value.0
+    }
+}
+
 /// Identifies an action and describes its default parameters.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ActionDescriptor<I: InputKind> {
@@ -194,6 +386,32 @@ pub struct DigitalResult {
     pub pressed: bool,
 }
 
+impl DigitalResult {
+    /// The state of a button which is not held, and has not changed this frame.
+    pub const NONE: Self = Self {
+        held: false,
+        released: false,
+        pressed: false,
+    };
+
+    /// Whether the button is currently pressed. This is equivalent to [`Self::held`].
+    pub const fn is_down(&self) -> bool {
+        self.held
+    }
+
+    /// Whether the button was held this frame but not last frame.
+    /// This is equivalent to [`Self::pressed`].
+    pub const fn just_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Whether the button was held last frame but not this frame.
+    /// This is equivalent to [`Self::released`].
+    pub const fn just_released(&self) -> bool {
+        self.released
+    }
+}
+
 /// Determines how a raw user input will affect an analog action.
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct AnalogBinding {
@@ -212,6 +430,30 @@ pub struct DigitalBinding {
     pub threshold: f32,
     /// The raw input to read.
     pub raw_input: RawInput,
+    /// Determines when the action is considered active.
+    #[serde(default)]
+    pub trigger: TriggerMode,
+}
+
+/// Determines when a digital action becomes active, relative to its raw input.
+/// The edge flags of the resulting [`DigitalResult`] are computed from whether
+/// the action is active, rather than from the raw input itself.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum TriggerMode {
+    /// The action is active for a single frame, when the raw input is first pressed.
+    /// [`DigitalResult::pressed`] and [`DigitalResult::held`] are set on that frame,
+    /// and [`DigitalResult::released`] is set on the following frame.
+    OnPress,
+    /// The action is active for a single frame, when the raw input is released.
+    /// [`DigitalResult::pressed`] and [`DigitalResult::held`] are set on that frame,
+    /// and [`DigitalResult::released`] is set on the following frame.
+    OnRelease,
+    /// The action is active for as long as the raw input is held, so the edge flags
+    /// of the result match those of the raw input.
+    #[default]
+    WhileHeld,
 }
 
 /// Identifies a source to which an action may be bound.
@@ -227,6 +469,81 @@ pub enum RawInput {
     MouseButton(MouseButton),
 }
 
+impl RawInput {
+    /// Iterates over every possible raw input.
+    pub fn all() -> impl Iterator<Item = RawInput> {
+        Key::ALL
+            .into_iter()
+            .map(RawInput::Key)
+            .chain(MouseButton::ALL.into_iter().map(RawInput::MouseButton))
+            .chain(GamepadButton::ALL.into_iter().map(RawInput::GamepadButton))
+            .chain(GamepadAxis::ALL.into_iter().map(RawInput::GamepadAxis))
+    }
+
+    /// Gets the kind of device that produces this input.
+    pub fn device_kind(&self) -> DeviceKind {
+        match self {
+            RawInput::GamepadAxis(_) | RawInput::GamepadButton(_) => DeviceKind::Gamepad,
+            RawInput::Key(_) | RawInput::MouseButton(_) => DeviceKind::KeyboardMouse,
+        }
+    }
+
+    /// Gets a human-readable label for this input, suitable for on-screen prompts.
+    /// Gamepad inputs are labeled with the default [`GamepadGlyphs`].
+    pub fn display_name(&self) -> &'static str {
+        self.display_name_with(GamepadGlyphs::default())
+    }
+
+    /// Gets a human-readable label for this input, suitable for on-screen prompts.
+    /// Gamepad inputs are labeled according to `glyphs`.
+    pub fn display_name_with(&self, glyphs: GamepadGlyphs) -> &'static str {
+        match self {
+            RawInput::GamepadAxis(x) => x.display_name(glyphs),
+            RawInput::GamepadButton(x) => x.display_name(glyphs),
+            RawInput::Key(x) => x.display_name(),
+            RawInput::MouseButton(x) => x.display_name(),
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl TryFrom<RawInput> for egui_wings::egui::PointerButton {
+    type Error = RawInput;
+
+    /// Converts a mouse button input to its `egui` equivalent.
+    /// Any other kind of input is returned unchanged.
+    fn try_from(value: RawInput) -> Result<Self, Self::Error> {
+        match value {
+            RawInput::MouseButton(x) => Ok(x.into()),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Identifies a category of input device.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum DeviceKind {
+    /// A gamepad or controller.
+    Gamepad,
+    /// A keyboard and mouse.
+    #[default]
+    KeyboardMouse,
+}
+
+/// Selects the style of labels used to describe gamepad inputs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GamepadGlyphs {
+    /// Labels in the style of Nintendo controllers.
+    Nintendo,
+    /// Labels in the style of PlayStation controllers.
+    PlayStation,
+    /// Labels in the style of Xbox controllers.
+    #[default]
+    Xbox,
+}
+
 /// Identifies a continuous axis on a gamepad, returning a value on the range `[-1.0, 1.0]`.
 /// Follows the [gilrs standard layout](https://docs.rs/gilrs/latest/gilrs/).
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -262,6 +579,141 @@ impl GamepadAxis {
         Self::DPadX,
         Self::DPadY,
     ];
+
+    /// Gets a human-readable label for this axis, using the provided style.
+    pub fn display_name(&self, glyphs: GamepadGlyphs) -> &'static str {
+        match (self, glyphs) {
+            (Self::LeftStickX, _) => "Left Stick X",
+            (Self::LeftStickY, _) => "Left Stick Y",
+            (Self::LeftZ, GamepadGlyphs::Nintendo) => "ZL",
+            (Self::LeftZ, GamepadGlyphs::PlayStation) => "L2",
+            (Self::LeftZ, GamepadGlyphs::Xbox) => "LT",
+            (Self::RightStickX, _) => "Right Stick X",
+            (Self::RightStickY, _) => "Right Stick Y",
+            (Self::RightZ, GamepadGlyphs::Nintendo) => "ZR",
+            (Self::RightZ, GamepadGlyphs::PlayStation) => "R2",
+            (Self::RightZ, GamepadGlyphs::Xbox) => "RT",
+            (Self::DPadX, _) => "D-Pad X",
+            (Self::DPadY, _) => "D-Pad Y",
+        }
+    }
+}
+
+/// Determines the shape of the mouse cursor.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+pub enum CursorIcon {
+    /// A crosshair, for precise selection.
+    Crosshair,
+    /// The platform's default arrow cursor.
+    #[default]
+    Default,
+    /// An open hand, indicating that something may be dragged.
+    Grab,
+    /// The cursor is not shown.
+    Hidden,
+    /// A pointing hand, indicating that something may be clicked.
+    Pointer,
+    /// A text cursor, indicating that text may be selected or edited.
+    Text,
+}
+
+/// Identifies a gamepad that is connected to the user's device.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct GamepadId(u64);
+
+impl From<u64> for GamepadId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<GamepadId> for u64 {
+    fn from(value: GamepadId) -> Self {
+        value.0
+    }
+}
+
+/// Holds the values of every axis and button on a gamepad during a single frame.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GamepadState {
+    /// The value of each axis, indexed in the order of [`GamepadAxis::ALL`].
+    pub axes: [f32; GamepadAxis::ALL.len()],
+    /// The value of each button, indexed in the order of [`GamepadButton::ALL`].
+    pub buttons: [f32; GamepadButton::ALL.len()],
+}
+
+impl GamepadState {
+    /// Gets the value of the given axis.
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes[axis as usize]
+    }
+
+    /// Gets the value of the given button.
+    pub fn button(&self, button: GamepadButton) -> f32 {
+        self.buttons[button as usize]
+    }
+}
+
+/// Identifies an adaptive trigger on a gamepad.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum GamepadTrigger {
+    /// The left trigger.
+    Left,
+    /// The right trigger.
+    Right,
+}
+
+/// Describes the resistance that an adaptive trigger applies as it is pulled.
+/// Positions are given on the range `[0.0, 1.0]`, where `0.0` is fully released
+/// and `1.0` is fully pulled. Forces are given on the range `[0.0, 1.0]`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum TriggerEffect {
+    /// The trigger applies no resistance.
+    #[default]
+    Off,
+    /// The trigger applies constant resistance past a certain position.
+    Constant {
+        /// The position at which resistance begins.
+        start: f32,
+        /// The strength of the resistance.
+        force: f32,
+    },
+    /// The trigger resists until it passes a certain position, and then snaps
+    /// back like the trigger of a gun.
+    Weapon {
+        /// The position at which resistance begins.
+        start: f32,
+        /// The position at which resistance ends.
+        end: f32,
+        /// The strength of the resistance.
+        force: f32,
+    },
+}
+
+/// Identifies a thumbstick on a gamepad.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum GamepadStick {
+    /// The left thumbstick.
+    Left,
+    /// The right thumbstick.
+    Right,
+}
+
+impl GamepadStick {
+    /// The radius of the circular region around the center of a stick
+    /// within which input is ignored.
+    pub const DEADZONE: f32 = 0.15;
+
+    /// Gets the horizontal and vertical axes of this stick.
+    pub const fn axes(self) -> (GamepadAxis, GamepadAxis) {
+        match self {
+            Self::Left => (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY),
+            Self::Right => (GamepadAxis::RightStickX, GamepadAxis::RightStickY),
+        }
+    }
 }
 
 /// Denotes a key on a user's keyboard.
@@ -619,6 +1071,343 @@ impl Key {
         Self::Period,
         Self::Slash,
     ];
+
+    /// Gets a human-readable label for this key.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+            Self::E => "E",
+            Self::F => "F",
+            Self::G => "G",
+            Self::H => "H",
+            Self::I => "I",
+            Self::J => "J",
+            Self::K => "K",
+            Self::L => "L",
+            Self::M => "M",
+            Self::N => "N",
+            Self::O => "O",
+            Self::P => "P",
+            Self::Q => "Q",
+            Self::R => "R",
+            Self::S => "S",
+            Self::T => "T",
+            Self::U => "U",
+            Self::V => "V",
+            Self::W => "W",
+            Self::X => "X",
+            Self::Y => "Y",
+            Self::Z => "Z",
+            Self::Escape => "Escape",
+            Self::F1 => "F1",
+            Self::F2 => "F2",
+            Self::F3 => "F3",
+            Self::F4 => "F4",
+            Self::F5 => "F5",
+            Self::F6 => "F6",
+            Self::F7 => "F7",
+            Self::F8 => "F8",
+            Self::F9 => "F9",
+            Self::F10 => "F10",
+            Self::F11 => "F11",
+            Self::F12 => "F12",
+            Self::F13 => "F13",
+            Self::F14 => "F14",
+            Self::F15 => "F15",
+            Self::F16 => "F16",
+            Self::F17 => "F17",
+            Self::F18 => "F18",
+            Self::F19 => "F19",
+            Self::F20 => "F20",
+            Self::F21 => "F21",
+            Self::F22 => "F22",
+            Self::F23 => "F23",
+            Self::F24 => "F24",
+            Self::Key1 => "1",
+            Self::Key2 => "2",
+            Self::Key3 => "3",
+            Self::Key4 => "4",
+            Self::Key5 => "5",
+            Self::Key6 => "6",
+            Self::Key7 => "7",
+            Self::Key8 => "8",
+            Self::Key9 => "9",
+            Self::Key0 => "0",
+            Self::Numlock => "Num Lock",
+            Self::Numpad0 => "Numpad 0",
+            Self::Numpad1 => "Numpad 1",
+            Self::Numpad2 => "Numpad 2",
+            Self::Numpad3 => "Numpad 3",
+            Self::Numpad4 => "Numpad 4",
+            Self::Numpad5 => "Numpad 5",
+            Self::Numpad6 => "Numpad 6",
+            Self::Numpad7 => "Numpad 7",
+            Self::Numpad8 => "Numpad 8",
+            Self::Numpad9 => "Numpad 9",
+            Self::NumpadPlus => "Numpad +",
+            Self::NumpadMinus => "Numpad -",
+            Self::NumpadAsterisk => "Numpad *",
+            Self::NumpadSlash => "Numpad /",
+            Self::NumpadDecimal => "Numpad .",
+            Self::NumpadEnter => "Numpad Enter",
+            Self::Snapshot => "Print Screen",
+            Self::ScrollLock => "Scroll Lock",
+            Self::Pause => "Pause",
+            Self::Insert => "Insert",
+            Self::Home => "Home",
+            Self::Delete => "Delete",
+            Self::End => "End",
+            Self::PageUp => "Page Up",
+            Self::PageDown => "Page Down",
+            Self::Left => "Left Arrow",
+            Self::Right => "Right Arrow",
+            Self::Up => "Up Arrow",
+            Self::Down => "Down Arrow",
+            Self::Grave => "`",
+            Self::Back => "Backspace",
+            Self::Tab => "Tab",
+            Self::CapitalLock => "Caps Lock",
+            Self::Return => "Enter",
+            Self::Space => "Space",
+            Self::LAlt => "Left Alt",
+            Self::RAlt => "Right Alt",
+            Self::LShift => "Left Shift",
+            Self::RShift => "Right Shift",
+            Self::LControl => "Left Ctrl",
+            Self::RControl => "Right Ctrl",
+            Self::LWin => "Left Super",
+            Self::RWin => "Right Super",
+            Self::Minus => "-",
+            Self::Equals => "=",
+            Self::LBracket => "[",
+            Self::RBracket => "]",
+            Self::Backslash => "\\",
+            Self::Semicolon => ";",
+            Self::Apostrophe => "'",
+            Self::Comma => ",",
+            Self::Period => ".",
+            Self::Slash => "/",
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl TryFrom<Key> for egui_wings::egui::Key {
+    type Error = Key;
+
+    /// Converts a key to its `egui` equivalent. Numpad keys map to the
+    /// corresponding digit or symbol. Modifier and lock keys have no `egui` equivalent,
+    /// since `egui` reports them through `Modifiers` instead, so the original key is returned.
+    fn try_from(value: Key) -> Result<Self, Self::Error> {
+        match value {
+            Key::A => Ok(Self::A),
+            Key::B => Ok(Self::B),
+            Key::C => Ok(Self::C),
+            Key::D => Ok(Self::D),
+            Key::E => Ok(Self::E),
+            Key::F => Ok(Self::F),
+            Key::G => Ok(Self::G),
+            Key::H => Ok(Self::H),
+            Key::I => Ok(Self::I),
+            Key::J => Ok(Self::J),
+            Key::K => Ok(Self::K),
+            Key::L => Ok(Self::L),
+            Key::M => Ok(Self::M),
+            Key::N => Ok(Self::N),
+            Key::O => Ok(Self::O),
+            Key::P => Ok(Self::P),
+            Key::Q => Ok(Self::Q),
+            Key::R => Ok(Self::R),
+            Key::S => Ok(Self::S),
+            Key::T => Ok(Self::T),
+            Key::U => Ok(Self::U),
+            Key::V => Ok(Self::V),
+            Key::W => Ok(Self::W),
+            Key::X => Ok(Self::X),
+            Key::Y => Ok(Self::Y),
+            Key::Z => Ok(Self::Z),
+            Key::Escape => Ok(Self::Escape),
+            Key::F1 => Ok(Self::F1),
+            Key::F2 => Ok(Self::F2),
+            Key::F3 => Ok(Self::F3),
+            Key::F4 => Ok(Self::F4),
+            Key::F5 => Ok(Self::F5),
+            Key::F6 => Ok(Self::F6),
+            Key::F7 => Ok(Self::F7),
+            Key::F8 => Ok(Self::F8),
+            Key::F9 => Ok(Self::F9),
+            Key::F10 => Ok(Self::F10),
+            Key::F11 => Ok(Self::F11),
+            Key::F12 => Ok(Self::F12),
+            Key::F13 => Ok(Self::F13),
+            Key::F14 => Ok(Self::F14),
+            Key::F15 => Ok(Self::F15),
+            Key::F16 => Ok(Self::F16),
+            Key::F17 => Ok(Self::F17),
+            Key::F18 => Ok(Self::F18),
+            Key::F19 => Ok(Self::F19),
+            Key::F20 => Ok(Self::F20),
+            Key::F21 => Ok(Self::F21),
+            Key::F22 => Ok(Self::F22),
+            Key::F23 => Ok(Self::F23),
+            Key::F24 => Ok(Self::F24),
+            Key::Key0 => Ok(Self::Num0),
+            Key::Key1 => Ok(Self::Num1),
+            Key::Key2 => Ok(Self::Num2),
+            Key::Key3 => Ok(Self::Num3),
+            Key::Key4 => Ok(Self::Num4),
+            Key::Key5 => Ok(Self::Num5),
+            Key::Key6 => Ok(Self::Num6),
+            Key::Key7 => Ok(Self::Num7),
+            Key::Key8 => Ok(Self::Num8),
+            Key::Key9 => Ok(Self::Num9),
+            Key::Numpad0 => Ok(Self::Num0),
+            Key::Numpad1 => Ok(Self::Num1),
+            Key::Numpad2 => Ok(Self::Num2),
+            Key::Numpad3 => Ok(Self::Num3),
+            Key::Numpad4 => Ok(Self::Num4),
+            Key::Numpad5 => Ok(Self::Num5),
+            Key::Numpad6 => Ok(Self::Num6),
+            Key::Numpad7 => Ok(Self::Num7),
+            Key::Numpad8 => Ok(Self::Num8),
+            Key::Numpad9 => Ok(Self::Num9),
+            Key::NumpadPlus => Ok(Self::Plus),
+            Key::NumpadMinus => Ok(Self::Minus),
+            Key::NumpadSlash => Ok(Self::Slash),
+            Key::NumpadDecimal => Ok(Self::Period),
+            Key::NumpadEnter => Ok(Self::Enter),
+            Key::Insert => Ok(Self::Insert),
+            Key::Home => Ok(Self::Home),
+            Key::Delete => Ok(Self::Delete),
+            Key::End => Ok(Self::End),
+            Key::PageUp => Ok(Self::PageUp),
+            Key::PageDown => Ok(Self::PageDown),
+            Key::Left => Ok(Self::ArrowLeft),
+            Key::Right => Ok(Self::ArrowRight),
+            Key::Up => Ok(Self::ArrowUp),
+            Key::Down => Ok(Self::ArrowDown),
+            Key::Grave => Ok(Self::Backtick),
+            Key::Back => Ok(Self::Backspace),
+            Key::Tab => Ok(Self::Tab),
+            Key::Return => Ok(Self::Enter),
+            Key::Space => Ok(Self::Space),
+            Key::Minus => Ok(Self::Minus),
+            Key::Equals => Ok(Self::Equals),
+            Key::LBracket => Ok(Self::OpenBracket),
+            Key::RBracket => Ok(Self::CloseBracket),
+            Key::Backslash => Ok(Self::Backslash),
+            Key::Semicolon => Ok(Self::Semicolon),
+            Key::Apostrophe => Ok(Self::Quote),
+            Key::Comma => Ok(Self::Comma),
+            Key::Period => Ok(Self::Period),
+            Key::Slash => Ok(Self::Slash),
+            _ => Err(value),
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl TryFrom<egui_wings::egui::Key> for Key {
+    type Error = egui_wings::egui::Key;
+
+    /// Converts an `egui` key to its equivalent. Digits and symbols map to keys on
+    /// the main keyboard rather than the numpad. Keys without a physical
+    /// equivalent, such as [`egui_wings::egui::Key::Copy`], return the original key.
+    fn try_from(value: egui_wings::egui::Key) -> Result<Self, Self::Error> {
+        match value {
+            egui_wings::egui::Key::A => Ok(Self::A),
+            egui_wings::egui::Key::ArrowDown => Ok(Self::Down),
+            egui_wings::egui::Key::ArrowLeft => Ok(Self::Left),
+            egui_wings::egui::Key::ArrowRight => Ok(Self::Right),
+            egui_wings::egui::Key::ArrowUp => Ok(Self::Up),
+            egui_wings::egui::Key::B => Ok(Self::B),
+            egui_wings::egui::Key::Backslash => Ok(Self::Backslash),
+            egui_wings::egui::Key::Backspace => Ok(Self::Back),
+            egui_wings::egui::Key::Backtick => Ok(Self::Grave),
+            egui_wings::egui::Key::C => Ok(Self::C),
+            egui_wings::egui::Key::CloseBracket => Ok(Self::RBracket),
+            egui_wings::egui::Key::Comma => Ok(Self::Comma),
+            egui_wings::egui::Key::D => Ok(Self::D),
+            egui_wings::egui::Key::Delete => Ok(Self::Delete),
+            egui_wings::egui::Key::E => Ok(Self::E),
+            egui_wings::egui::Key::End => Ok(Self::End),
+            egui_wings::egui::Key::Enter => Ok(Self::Return),
+            egui_wings::egui::Key::Equals => Ok(Self::Equals),
+            egui_wings::egui::Key::Escape => Ok(Self::Escape),
+            egui_wings::egui::Key::F => Ok(Self::F),
+            egui_wings::egui::Key::F1 => Ok(Self::F1),
+            egui_wings::egui::Key::F10 => Ok(Self::F10),
+            egui_wings::egui::Key::F11 => Ok(Self::F11),
+            egui_wings::egui::Key::F12 => Ok(Self::F12),
+            egui_wings::egui::Key::F13 => Ok(Self::F13),
+            egui_wings::egui::Key::F14 => Ok(Self::F14),
+            egui_wings::egui::Key::F15 => Ok(Self::F15),
+            egui_wings::egui::Key::F16 => Ok(Self::F16),
+            egui_wings::egui::Key::F17 => Ok(Self::F17),
+            egui_wings::egui::Key::F18 => Ok(Self::F18),
+            egui_wings::egui::Key::F19 => Ok(Self::F19),
+            egui_wings::egui::Key::F2 => Ok(Self::F2),
+            egui_wings::egui::Key::F20 => Ok(Self::F20),
+            egui_wings::egui::Key::F21 => Ok(Self::F21),
+            egui_wings::egui::Key::F22 => Ok(Self::F22),
+            egui_wings::egui::Key::F23 => Ok(Self::F23),
+            egui_wings::egui::Key::F24 => Ok(Self::F24),
+            egui_wings::egui::Key::F3 => Ok(Self::F3),
+            egui_wings::egui::Key::F4 => Ok(Self::F4),
+            egui_wings::egui::Key::F5 => Ok(Self::F5),
+            egui_wings::egui::Key::F6 => Ok(Self::F6),
+            egui_wings::egui::Key::F7 => Ok(Self::F7),
+            egui_wings::egui::Key::F8 => Ok(Self::F8),
+            egui_wings::egui::Key::F9 => Ok(Self::F9),
+            egui_wings::egui::Key::G => Ok(Self::G),
+            egui_wings::egui::Key::H => Ok(Self::H),
+            egui_wings::egui::Key::Home => Ok(Self::Home),
+            egui_wings::egui::Key::I => Ok(Self::I),
+            egui_wings::egui::Key::Insert => Ok(Self::Insert),
+            egui_wings::egui::Key::J => Ok(Self::J),
+            egui_wings::egui::Key::K => Ok(Self::K),
+            egui_wings::egui::Key::L => Ok(Self::L),
+            egui_wings::egui::Key::M => Ok(Self::M),
+            egui_wings::egui::Key::Minus => Ok(Self::Minus),
+            egui_wings::egui::Key::N => Ok(Self::N),
+            egui_wings::egui::Key::Num0 => Ok(Self::Key0),
+            egui_wings::egui::Key::Num1 => Ok(Self::Key1),
+            egui_wings::egui::Key::Num2 => Ok(Self::Key2),
+            egui_wings::egui::Key::Num3 => Ok(Self::Key3),
+            egui_wings::egui::Key::Num4 => Ok(Self::Key4),
+            egui_wings::egui::Key::Num5 => Ok(Self::Key5),
+            egui_wings::egui::Key::Num6 => Ok(Self::Key6),
+            egui_wings::egui::Key::Num7 => Ok(Self::Key7),
+            egui_wings::egui::Key::Num8 => Ok(Self::Key8),
+            egui_wings::egui::Key::Num9 => Ok(Self::Key9),
+            egui_wings::egui::Key::O => Ok(Self::O),
+            egui_wings::egui::Key::OpenBracket => Ok(Self::LBracket),
+            egui_wings::egui::Key::P => Ok(Self::P),
+            egui_wings::egui::Key::PageDown => Ok(Self::PageDown),
+            egui_wings::egui::Key::PageUp => Ok(Self::PageUp),
+            egui_wings::egui::Key::Period => Ok(Self::Period),
+            egui_wings::egui::Key::Plus => Ok(Self::NumpadPlus),
+            egui_wings::egui::Key::Q => Ok(Self::Q),
+            egui_wings::egui::Key::Quote => Ok(Self::Apostrophe),
+            egui_wings::egui::Key::R => Ok(Self::R),
+            egui_wings::egui::Key::S => Ok(Self::S),
+            egui_wings::egui::Key::Semicolon => Ok(Self::Semicolon),
+            egui_wings::egui::Key::Slash => Ok(Self::Slash),
+            egui_wings::egui::Key::Space => Ok(Self::Space),
+            egui_wings::egui::Key::T => Ok(Self::T),
+            egui_wings::egui::Key::Tab => Ok(Self::Tab),
+            egui_wings::egui::Key::U => Ok(Self::U),
+            egui_wings::egui::Key::V => Ok(Self::V),
+            egui_wings::egui::Key::W => Ok(Self::W),
+            egui_wings::egui::Key::X => Ok(Self::X),
+            egui_wings::egui::Key::Y => Ok(Self::Y),
+            egui_wings::egui::Key::Z => Ok(Self::Z),
+            _ => Err(value),
+        }
+    }
 }
 
 /// Identifies a button on a controller, following the [gilrs standard layout](https://docs.rs/gilrs/latest/gilrs/).
@@ -688,6 +1477,58 @@ impl GamepadButton {
         Self::DPadLeft,
         Self::DPadRight,
     ];
+
+    /// Gets a human-readable label for this button, using the provided style.
+    /// Buttons are labeled by their position, so the [`GamepadButton::South`]
+    /// button is labeled `"A Button"` on Xbox controllers but `"B Button"`
+    /// on Nintendo controllers.
+    pub fn display_name(&self, glyphs: GamepadGlyphs) -> &'static str {
+        match (self, glyphs) {
+            (Self::South, GamepadGlyphs::Nintendo) => "B Button",
+            (Self::South, GamepadGlyphs::PlayStation) => "Cross",
+            (Self::South, GamepadGlyphs::Xbox) => "A Button",
+            (Self::East, GamepadGlyphs::Nintendo) => "A Button",
+            (Self::East, GamepadGlyphs::PlayStation) => "Circle",
+            (Self::East, GamepadGlyphs::Xbox) => "B Button",
+            (Self::North, GamepadGlyphs::Nintendo) => "X Button",
+            (Self::North, GamepadGlyphs::PlayStation) => "Triangle",
+            (Self::North, GamepadGlyphs::Xbox) => "Y Button",
+            (Self::West, GamepadGlyphs::Nintendo) => "Y Button",
+            (Self::West, GamepadGlyphs::PlayStation) => "Square",
+            (Self::West, GamepadGlyphs::Xbox) => "X Button",
+            (Self::C, _) => "C Button",
+            (Self::Z, _) => "Z Button",
+            (Self::LeftTrigger, GamepadGlyphs::Nintendo) => "L",
+            (Self::LeftTrigger, GamepadGlyphs::PlayStation) => "L1",
+            (Self::LeftTrigger, GamepadGlyphs::Xbox) => "LB",
+            (Self::LeftTrigger2, GamepadGlyphs::Nintendo) => "ZL",
+            (Self::LeftTrigger2, GamepadGlyphs::PlayStation) => "L2",
+            (Self::LeftTrigger2, GamepadGlyphs::Xbox) => "LT",
+            (Self::RightTrigger, GamepadGlyphs::Nintendo) => "R",
+            (Self::RightTrigger, GamepadGlyphs::PlayStation) => "R1",
+            (Self::RightTrigger, GamepadGlyphs::Xbox) => "RB",
+            (Self::RightTrigger2, GamepadGlyphs::Nintendo) => "ZR",
+            (Self::RightTrigger2, GamepadGlyphs::PlayStation) => "R2",
+            (Self::RightTrigger2, GamepadGlyphs::Xbox) => "RT",
+            (Self::Select, GamepadGlyphs::Nintendo) => "Minus Button",
+            (Self::Select, GamepadGlyphs::PlayStation) => "Share",
+            (Self::Select, GamepadGlyphs::Xbox) => "View",
+            (Self::Start, GamepadGlyphs::Nintendo) => "Plus Button",
+            (Self::Start, GamepadGlyphs::PlayStation) => "Options",
+            (Self::Start, GamepadGlyphs::Xbox) => "Menu",
+            (Self::Mode, GamepadGlyphs::Nintendo) => "Home Button",
+            (Self::Mode, GamepadGlyphs::PlayStation) => "PS Button",
+            (Self::Mode, GamepadGlyphs::Xbox) => "Xbox Button",
+            (Self::LeftThumb, GamepadGlyphs::PlayStation) => "L3",
+            (Self::LeftThumb, _) => "Left Stick",
+            (Self::RightThumb, GamepadGlyphs::PlayStation) => "R3",
+            (Self::RightThumb, _) => "Right Stick",
+            (Self::DPadUp, _) => "D-Pad Up",
+            (Self::DPadDown, _) => "D-Pad Down",
+            (Self::DPadLeft, _) => "D-Pad Left",
+            (Self::DPadRight, _) => "D-Pad Right",
+        }
+    }
 }
 
 /// Indicates a button on the user's mouse.
@@ -705,6 +1546,149 @@ pub enum MouseButton {
 impl MouseButton {
     /// A list of all possible mouse button values.
     pub const ALL: [Self; 3] = [Self::Left, Self::Middle, Self::Right];
+
+    /// Gets a human-readable label for this button.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Left => "Left Mouse",
+            Self::Middle => "Middle Mouse",
+            Self::Right => "Right Mouse",
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl From<MouseButton> for egui_wings::egui::PointerButton {
+    fn from(value: MouseButton) -> Self {
+        match value {
+            MouseButton::Left => Self::Primary,
+            MouseButton::Middle => Self::Middle,
+            MouseButton::Right => Self::Secondary,
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl TryFrom<egui_wings::egui::PointerButton> for MouseButton {
+    type Error = egui_wings::egui::PointerButton;
+
+    /// Converts an `egui` pointer button to its equivalent. The extra mouse
+    /// buttons are not supported, so the original button is returned for them.
+    fn try_from(value: egui_wings::egui::PointerButton) -> Result<Self, Self::Error> {
+        match value {
+            egui_wings::egui::PointerButton::Primary => Ok(Self::Left),
+            egui_wings::egui::PointerButton::Middle => Ok(Self::Middle),
+            egui_wings::egui::PointerButton::Secondary => Ok(Self::Right),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A rectangular region of the screen, in pixels relative to the top-left corner of the window.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScreenRect {
+    /// The top-left corner of the region.
+    pub min: Vec2,
+    /// The bottom-right corner of the region.
+    pub max: Vec2,
+}
+
+impl ScreenRect {
+    /// Creates a new region from its top-left and bottom-right corners.
+    pub fn new(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    /// Whether the given position lies within this region, inclusive of its boundary.
+    pub fn contains(&self, position: Vec2) -> bool {
+        self.min.cmple(position).all() && position.cmple(self.max).all()
+    }
+
+    /// Gets the width and height of this region.
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+}
+
+/// Describes a single point at which the user is touching the screen.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Touch {
+    /// An identifier which remains the same for as long as this finger touches the screen.
+    pub id: u64,
+    /// The current stage of the touch.
+    pub phase: TouchPhase,
+    /// The position of the touch in pixels, relative to the top-left corner of the window.
+    pub position: Vec2,
+}
+
+/// Identifies the stage of a touch's lifetime.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum TouchPhase {
+    /// The touch was interrupted, such as by the window losing focus.
+    Cancelled,
+    /// The finger was lifted from the screen.
+    Ended,
+    /// The finger moved across the screen.
+    Moved,
+    /// The finger was placed on the screen.
+    #[default]
+    Started,
+}
+
+/// Identifies an on-screen joystick registered with [`Input::define_virtual_stick`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct VirtualStickId(u64);
+
+impl From<u64> for VirtualStickId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<VirtualStickId> for u64 {
+    fn from(value: VirtualStickId) -> Self {
+        value.0
+    }
+}
+
+/// The set of events that this module raises.
+pub mod on {
+    use super::*;
+
+    /// Raised whenever the pointer becomes locked or unlocked, whether because of a call to
+    /// [`Input::set_pointer_locked`] or because the platform released the lock.
+    #[derive(Clone, Debug, Default)]
+    #[export_type]
+    pub struct PointerLockChanged {
+        /// Whether the pointer is now locked.
+        pub locked: bool,
+    }
+
+    /// Raised when a finger is placed on the screen.
+    #[derive(Clone, Debug, Default)]
+    #[export_type]
+    pub struct TouchStart {
+        /// The touch that started.
+        pub touch: Touch,
+    }
+
+    /// Raised when a finger moves across the screen.
+    #[derive(Clone, Debug, Default)]
+    #[export_type]
+    pub struct TouchMove {
+        /// The touch that moved.
+        pub touch: Touch,
+    }
+
+    /// Raised when a finger is lifted from the screen, or the touch is cancelled.
+    #[derive(Clone, Debug, Default)]
+    #[export_type]
+    pub struct TouchEnd {
+        /// The touch that ended.
+        pub touch: Touch,
+    }
 }
 
 /// Hides internal implementation details.
@@ -18,9 +18,21 @@ pub mod physics;
 /// Allows for manipulating the camera and player.
 pub mod player;
 
+/// Allows for drawing custom geometry in the world.
+pub mod render;
+
+/// Describes the serialized shape of types shared between the host and guests.
+pub mod schema;
+
+/// Provides single-threaded alternatives to threading and synchronization primitives.
+pub mod sync;
+
 /// Facilitates access to frame and tick timing data.
 pub mod timing;
 
+/// Allows for generating and editing the voxel world.
+pub mod world;
+
 /// Marks systems that will be instantiated on the game client.
 #[derive(Copy, Clone, Debug)]
 #[export_type]
@@ -47,6 +59,106 @@ pub trait Logger: 'static {
     /// Prints a log message with the specified level.
     #[global(global_log)]
     fn log(&self, level: LogLevel, message: &str);
+
+    /// Removes the innermost span from the current span stack.
+    #[doc(hidden)]
+    #[global(global_pop_span)]
+    fn pop_span(&self);
+
+    /// Adds a span with the given name to the current span stack.
+    #[doc(hidden)]
+    fn push_span(&self, name: &str);
+}
+
+impl dyn Logger {
+    /// Logs an error with the given message if `condition` is false. Unlike
+    /// [`debug_assert!`], this does not panic, so the plugin continues running.
+    /// Like [`debug_assert!`], the check is skipped when debug assertions are disabled.
+    pub fn debug_assert_log(&self, condition: bool, message: &str) {
+        if cfg!(debug_assertions) && !condition {
+            self.log(LogLevel::Error, &format!("Assertion failed: {message}"));
+        }
+    }
+
+    /// Enters a named span. Until the returned guard is dropped, all subsequent log
+    /// messages are tagged with the span's name and indented beneath it. Spans may
+    /// be nested, in which case messages are prefixed with the whole span stack.
+    pub fn enter_span(&self, name: &str) -> LogSpan {
+        self.push_span(name);
+        LogSpan { _private: () }
+    }
+
+    /// Logs a labeled hexdump of the given bytes. Each line of the dump holds sixteen bytes,
+    /// beginning with their offset and followed by their values in hexadecimal and ASCII.
+    /// Non-printable characters are shown as `.` in the ASCII column.
+    pub fn log_bytes(&self, level: LogLevel, label: &str, bytes: &[u8]) {
+        let mut message = format!("{label} ({} bytes)", bytes.len());
+
+        for (i, line) in bytes.chunks(16).enumerate() {
+            let hex = line
+                .iter()
+                .map(|x| format!("{x:02x}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ascii = line
+                .iter()
+                .map(|&x| {
+                    if x.is_ascii_graphic() || x == b' ' {
+                        x as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+
+            message += &format!("\n{:08x}  {hex:<47}  |{ascii}|", 16 * i);
+        }
+
+        self.log(level, &message);
+    }
+
+    /// Unwraps the given result, or logs the error at the given level and returns `None`.
+    /// This allows mods to surface failures without panicking and tearing down the plugin.
+    pub fn log_result<T, E: std::fmt::Debug>(
+        &self,
+        result: Result<T, E>,
+        level: LogLevel,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.log(level, &format!("{error:?}"));
+                None
+            }
+        }
+    }
+}
+
+/// A guard which keeps a log span active until it is dropped.
+#[must_use = "the span is exited immediately if the guard is not held"]
+#[derive(Debug)]
+pub struct LogSpan {
+    /// Prevents this type from being constructed outside of this crate.
+    _private: (),
+}
+
+impl Drop for LogSpan {
+    fn drop(&mut self) {
+        global_pop_span();
+    }
+}
+
+/// Allows for reporting numeric time-series data to the engine's profiler. Unlike log
+/// messages, metrics are aggregated by the host once per frame and may be graphed.
+#[system_trait(host)]
+pub trait Metrics: 'static {
+    /// Adds the given amount to the named counter. Counters are summed over each frame,
+    /// and reset to zero at the start of the next one.
+    fn increment_counter(&self, name: &str, by: f64);
+
+    /// Records a sample of the named metric. If several samples are recorded
+    /// during the same frame, the most recent one is used.
+    fn record_metric(&self, name: &str, value: f64);
 }
 
 /// Determines the severity of a log message.
@@ -0,0 +1,152 @@
+use super::*;
+
+/// A color with red, green, blue, and alpha components. The color components
+/// are sRGB-encoded, and all components lie on the range `[0.0, 1.0]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize, Pod, Zeroable)]
+pub struct Rgba {
+    /// The red component.
+    pub r: f32,
+    /// The green component.
+    pub g: f32,
+    /// The blue component.
+    pub b: f32,
+    /// The alpha component, where `0.0` is fully transparent and `1.0` is fully opaque.
+    pub a: f32,
+}
+
+impl Rgba {
+    /// Opaque black.
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    /// Fully-transparent black.
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Opaque white.
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+
+    /// Converts from the Oklab color space to linear RGB.
+    const LMS_TO_LINEAR: Mat3 = Mat3::from_cols(
+        Vec3::new(4.076_741_7, -1.268_438, -0.0041960863),
+        Vec3::new(-3.307_711_6, 2.609_757_4, -0.703_418_6),
+        Vec3::new(0.230_969_94, -0.341_319_38, 1.707_614_7),
+    );
+
+    /// Converts from linear RGB to the LMS cone responses used by Oklab.
+    const LINEAR_TO_LMS: Mat3 = Mat3::from_cols(
+        Vec3::new(0.412_221_46, 0.211_903_5, 0.088_302_46),
+        Vec3::new(0.536_332_55, 0.680_699_5, 0.281_718_85),
+        Vec3::new(0.051_445_995, 0.107_396_96, 0.629_978_7),
+    );
+
+    /// Converts from nonlinear LMS cone responses to Oklab.
+    const LMS_TO_OKLAB: Mat3 = Mat3::from_cols(
+        Vec3::new(0.210_454_26, 1.977_998_5, 0.025_904_037),
+        Vec3::new(0.793_617_8, -2.428_592_2, 0.782_771_77),
+        Vec3::new(-0.004_072_047, 0.450_593_7, -0.808_675_77),
+    );
+
+    /// Converts from Oklab to nonlinear LMS cone responses.
+    const OKLAB_TO_LMS: Mat3 = Mat3::from_cols(
+        Vec3::new(1.0, 1.0, 1.0),
+        Vec3::new(0.396_337_78, -0.105_561_346, -0.089_484_18),
+        Vec3::new(0.215_803_76, -0.063_854_17, -1.291_485_5),
+    );
+
+    /// Creates a new color from sRGB-encoded components.
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a color from hue, saturation, value, and alpha components. The hue is given
+    /// in turns, so that `0.0` and `1.0` both correspond to red. All other components lie
+    /// on the range `[0.0, 1.0]`.
+    pub fn from_hsv(h: f32, s: f32, v: f32, a: f32) -> Self {
+        let sector = 6.0 * h.rem_euclid(1.0);
+        let chroma = v * s;
+        let x = chroma * (1.0 - ((sector % 2.0) - 1.0).abs());
+        let m = v - chroma;
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Self::new(r + m, g + m, b + m, a)
+    }
+
+    /// Converts this color to hue, saturation, value, and alpha components.
+    /// See [`Self::from_hsv`] for a description of each component.
+    pub fn to_hsv(&self) -> (f32, f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+
+        let sector = if delta == 0.0 {
+            0.0
+        } else if max == self.r {
+            ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            (self.b - self.r) / delta + 2.0
+        } else {
+            (self.r - self.g) / delta + 4.0
+        };
+
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+        (sector / 6.0, s, max, self.a)
+    }
+
+    /// Interpolates between two colors in the perceptually-uniform Oklab color space,
+    /// which avoids the muddy intermediate colors produced by interpolating sRGB components.
+    /// The alpha component is interpolated linearly.
+    pub fn lerp_oklab(&self, other: &Self, t: f32) -> Self {
+        let lab = self.to_oklab().lerp(other.to_oklab(), t);
+        Self::from_oklab(lab, self.a + (other.a - self.a) * t)
+    }
+
+    /// Converts an Oklab color to sRGB.
+    fn from_oklab(lab: Vec3, a: f32) -> Self {
+        let lms = Self::OKLAB_TO_LMS * lab;
+        let linear = Self::LMS_TO_LINEAR * (lms * lms * lms);
+        Self::new(
+            linear_to_srgb(linear.x),
+            linear_to_srgb(linear.y),
+            linear_to_srgb(linear.z),
+            a,
+        )
+    }
+
+    /// Converts the color components of this value to Oklab.
+    fn to_oklab(self) -> Vec3 {
+        let linear = vec3(
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        );
+        let lms = Self::LINEAR_TO_LMS * linear;
+        Self::LMS_TO_OKLAB * vec3(lms.x.cbrt(), lms.y.cbrt(), lms.z.cbrt())
+    }
+}
+
+/// Converts a linear color component to an sRGB-encoded one.
+fn linear_to_srgb(x: f32) -> f32 {
+    let x = x.clamp(0.0, 1.0);
+    if x <= 0.0031308 {
+        12.92 * x
+    } else {
+        1.055 * x.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts an sRGB-encoded color component to a linear one.
+fn srgb_to_linear(x: f32) -> f32 {
+    if x <= 0.04045 {
+        x / 12.92
+    } else {
+        ((x + 0.055) / 1.055).powf(2.4)
+    }
+}
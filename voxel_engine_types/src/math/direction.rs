@@ -25,6 +25,18 @@ pub enum Octant {
 }
 
 impl Octant {
+    /// A list of all eight octants in lexical order.
+    pub const ALL: [Self; 8] = [
+        Self::Z0Y0X0,
+        Self::Z0Y0X1,
+        Self::Z0Y1X0,
+        Self::Z0Y1X1,
+        Self::Z1Y0X0,
+        Self::Z1Y0X1,
+        Self::Z1Y1X0,
+        Self::Z1Y1X1,
+    ];
+
     /// Converts the raw bits into a voxel octant.
     ///
     /// # Safety
@@ -50,6 +62,12 @@ impl Octant {
         )
     }
 
+    /// Gets the offset of the unit octant corresponding to this value,
+    /// as a floating-point vector with components of either `0.0` or `1.0`.
+    pub fn as_corner_vec3a(self) -> Vec3A {
+        self.as_uvec3().as_vec3a()
+    }
+
     /// Inverts the position of this octant along the specified axis.
     pub fn flip(self, axis: Axis) -> Octant {
         unsafe { Self::from_raw((self as u8) ^ (1 << (axis as u8))) }
@@ -58,16 +76,7 @@ impl Octant {
     /// An array which lists all eight octants in lexical order.
     #[inline(always)]
     pub fn lexical_order() -> &'static [Octant; 8] {
-        &[
-            Octant::Z0Y0X0,
-            Octant::Z0Y0X1,
-            Octant::Z0Y1X0,
-            Octant::Z0Y1X1,
-            Octant::Z1Y0X0,
-            Octant::Z1Y0X1,
-            Octant::Z1Y1X0,
-            Octant::Z1Y1X1,
-        ]
+        &Self::ALL
     }
 }
 
@@ -87,7 +96,7 @@ impl From<Octant> for UVec3 {
 }
 
 /// Represents a selection of multiple octants within an octree.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Pod, Zeroable)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Pod, Zeroable)]
 #[repr(transparent)]
 pub struct OctantFlags(u8);
 
@@ -132,6 +141,30 @@ impl OctantFlags {
     pub const fn contains(self, other: Self) -> bool {
         (self.0 & other.0) == other.0
     }
+
+    /// Gets the flags which are in `self` but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Whether all of the flags in `self` are also in `other`.
+    pub const fn is_subset_of(self, other: Self) -> bool {
+        other.contains(self)
+    }
+
+    /// Whether all of the flags in `other` are also in `self`.
+    /// This is equivalent to [`Self::contains`].
+    pub const fn is_superset_of(self, other: Self) -> bool {
+        self.contains(other)
+    }
+
+    /// Gets the set of cube faces touched by any of these octants. This is the
+    /// counterpart to [`DirectionFlags::to_octant_flags`].
+    pub fn bounding_directions(self) -> DirectionFlags {
+        self.into_iter().fold(DirectionFlags::NONE, |acc, x| {
+            acc | DirectionFlags::from_octant(x)
+        })
+    }
 }
 
 impl BitOr for OctantFlags {
@@ -162,6 +195,20 @@ impl BitAndAssign for OctantFlags {
     }
 }
 
+impl BitXor for OctantFlags {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl BitXorAssign for OctantFlags {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
 impl Not for OctantFlags {
     type Output = Self;
 
@@ -176,6 +223,18 @@ impl From<Octant> for OctantFlags {
     }
 }
 
+impl std::fmt::Debug for OctantFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("OctantFlags({self})"))
+    }
+}
+
+impl std::fmt::Display for OctantFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_flags(f, *self)
+    }
+}
+
 impl IntoIterator for OctantFlags {
     type Item = Octant;
 
@@ -194,7 +253,7 @@ impl IntoIterator for OctantFlags {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct DirectionFlags(u8);
 
@@ -265,6 +324,32 @@ impl DirectionFlags {
         (self.0 & other.0) == other.0
     }
 
+    /// Gets the flags which are in `self` but not in `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Whether all of the flags in `self` are also in `other`.
+    pub const fn is_subset_of(self, other: Self) -> bool {
+        other.contains(self)
+    }
+
+    /// Whether all of the flags in `other` are also in `self`.
+    /// This is equivalent to [`Self::contains`].
+    pub const fn is_superset_of(self, other: Self) -> bool {
+        self.contains(other)
+    }
+
+    /// Gets the set of octants which touch any of these cube faces,
+    /// according to [`DIRECTION_OCTANT_MAP`].
+    pub fn to_octant_flags(self) -> OctantFlags {
+        self.into_iter().fold(OctantFlags::NONE, |acc, x| {
+            DIRECTION_OCTANT_MAP[x]
+                .into_iter()
+                .fold(acc, |acc, y| acc | OctantFlags::from_octant(y))
+        })
+    }
+
     /// Constructs a new set of direction flags from the underlying bits,
     /// ignoring any extra bits in the mask.
     pub const fn from_bits_truncate(bits: u8) -> Self {
@@ -314,6 +399,20 @@ impl BitAndAssign for DirectionFlags {
     }
 }
 
+impl BitXor for DirectionFlags {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl BitXorAssign for DirectionFlags {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
 impl Not for DirectionFlags {
     type Output = Self;
 
@@ -345,6 +444,18 @@ impl IntoIterator for DirectionFlags {
     }
 }
 
+impl std::fmt::Debug for DirectionFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("DirectionFlags({self})"))
+    }
+}
+
+impl std::fmt::Display for DirectionFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_flags(f, *self)
+    }
+}
+
 impl From<DirectionFlags> for IVec3 {
     fn from(x: DirectionFlags) -> Self {
         x.into_iter()
@@ -371,6 +482,16 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// A list of all six directions in standard order.
+    pub const ALL: [Self; 6] = [
+        Self::LEFT,
+        Self::RIGHT,
+        Self::DOWN,
+        Self::UP,
+        Self::BACK,
+        Self::FRONT,
+    ];
+
     /// Creates a new direction from a raw byte.
     ///
     /// # Safety
@@ -380,6 +501,33 @@ impl Direction {
         transmute(bits)
     }
 
+    /// Finds the cardinal direction closest to `v`, determined by its component with the
+    /// largest magnitude. Ties are broken in favor of the x-axis, then the y-axis. Zero
+    /// components are treated as positive, so a zero vector maps to [`Direction::RIGHT`].
+    pub fn nearest(v: Vec3A) -> Self {
+        let abs = v.abs();
+        let axis = if abs.y <= abs.x && abs.z <= abs.x {
+            Axis::X
+        } else if abs.z <= abs.y {
+            Axis::Y
+        } else {
+            Axis::Z
+        };
+
+        if v[axis] < 0.0 {
+            axis.as_direction_negative()
+        } else {
+            axis.as_direction_positive()
+        }
+    }
+
+    /// Finds the cardinal direction that `v` points along, or returns `None` if `v` lies
+    /// farther than `tolerance` from the unit vector of every cardinal direction.
+    pub fn try_from_unit(v: Vec3A, tolerance: f32) -> Option<Self> {
+        let result = Self::nearest(v);
+        (v.distance(result.as_vec3a()) <= tolerance).then_some(result)
+    }
+
     /// Returns a unit-length offset in the direction
     /// described by this value.
     pub const fn offset(self) -> IVec3 {
@@ -393,6 +541,12 @@ impl Direction {
         }
     }
 
+    /// Returns a unit-length floating-point vector in the direction
+    /// described by this value.
+    pub fn as_vec3a(self) -> Vec3A {
+        self.offset().as_vec3a()
+    }
+
     /// Returns the opposite of this direction.
     pub const fn reverse(self) -> Self {
         unsafe { Self::from_raw((self as u8) ^ 1) }
@@ -402,6 +556,19 @@ impl Direction {
     pub fn positive(self) -> bool {
         self as u8 % 2 == 1
     }
+
+    /// Returns two directions which lie in the plane perpendicular to this one, forming a
+    /// right-handed basis with it: the cross product of the first and second directions
+    /// is always equal to this direction. This is useful for generating consistent
+    /// texture coordinates and winding orders for voxel faces.
+    pub fn tangents(self) -> (Direction, Direction) {
+        let (a, b) = Axis::from_direction(self).others();
+        if self.positive() {
+            (a.as_direction_positive(), b.as_direction_positive())
+        } else {
+            (b.as_direction_positive(), a.as_direction_positive())
+        }
+    }
 }
 
 impl From<Direction> for IVec3 {
@@ -462,6 +629,16 @@ impl Axis {
     pub const fn as_direction_positive(self) -> Direction {
         unsafe { Direction::from_raw(((self as u8) << 1) | 1) }
     }
+
+    /// Returns the other two axes in cyclic order, so that the cross product of
+    /// the first and second axes is always equal to this axis.
+    pub const fn others(self) -> (Axis, Axis) {
+        match self {
+            Axis::X => (Axis::Y, Axis::Z),
+            Axis::Y => (Axis::Z, Axis::X),
+            Axis::Z => (Axis::X, Axis::Y),
+        }
+    }
 }
 
 impl From<Direction> for Axis {
@@ -534,6 +711,58 @@ impl IndexMut<Axis> for Vec3A {
     }
 }
 
+impl Index<Axis> for WorldVec {
+    type Output = WorldCoord;
+
+    fn index(&self, index: Axis) -> &Self::Output {
+        match index {
+            Axis::X => &self.x,
+            Axis::Y => &self.y,
+            Axis::Z => &self.z,
+        }
+    }
+}
+
+impl IndexMut<Axis> for WorldVec {
+    fn index_mut(&mut self, index: Axis) -> &mut Self::Output {
+        match index {
+            Axis::X => &mut self.x,
+            Axis::Y => &mut self.y,
+            Axis::Z => &mut self.z,
+        }
+    }
+}
+
+/// Rearranges the components of a vector so that the `primary` axis becomes the z-axis.
+/// The other two axes become x and y in the cyclic order given by [`Axis::others`], so
+/// the handedness of the coordinate system is preserved. This allows code written for
+/// a single axis to operate on any axis. See [`swizzle_from_axis`] for the inverse.
+pub fn swizzle_to_axis<V: Copy + IndexMut<Axis>>(v: V, primary: Axis) -> V
+where
+    V::Output: Copy,
+{
+    let (first, second) = primary.others();
+    let mut result = v;
+    result[Axis::X] = v[first];
+    result[Axis::Y] = v[second];
+    result[Axis::Z] = v[primary];
+    result
+}
+
+/// Undoes the rearrangement performed by [`swizzle_to_axis`], moving
+/// the z-axis back to the `primary` axis.
+pub fn swizzle_from_axis<V: Copy + IndexMut<Axis>>(v: V, primary: Axis) -> V
+where
+    V::Output: Copy,
+{
+    let (first, second) = primary.others();
+    let mut result = v;
+    result[first] = v[Axis::X];
+    result[second] = v[Axis::Y];
+    result[primary] = v[Axis::Z];
+    result
+}
+
 /// Provides a way to map between Cartesian cardinal directions and
 /// another type.
 #[derive(Copy, Clone, Debug, Default)]
@@ -570,6 +799,15 @@ impl<T> DirectionMap<T> {
         Self(directions)
     }
 
+    /// Creates a direction map from two arrays. The first array holds the items for
+    /// the left, down, and back directions, while the second array holds the items
+    /// for the right, up, and front directions.
+    pub fn from_axis_pairs(negatives: [T; 3], positives: [T; 3]) -> Self {
+        let [nx, ny, nz] = negatives;
+        let [px, py, pz] = positives;
+        Self([nx, px, ny, py, nz, pz])
+    }
+
     /// Gets an immutable reference to the item associated with the specified direction.
     pub fn get(&self, direction: Direction) -> &T {
         unsafe { self.0.get_unchecked(direction as usize) }
@@ -586,6 +824,11 @@ impl<T> DirectionMap<T> {
         self.into_iter()
     }
 
+    /// Consumes the map, returning an iterator over its items in standard order.
+    pub fn into_values(self) -> std::array::IntoIter<T, 6> {
+        self.0.into_iter()
+    }
+
     /// Transforms the items in the map into a new map.
     pub fn map<U, F: FnMut(Direction, T) -> U>(self, mut f: F) -> DirectionMap<U> {
         let [i0, i1, i2, i3, i4, i5] = self.0;
@@ -642,6 +885,24 @@ impl<'a, T> IntoIterator for &'a DirectionMap<T> {
     }
 }
 
+impl<T> IntoIterator for DirectionMap<T> {
+    type Item = (Direction, T);
+
+    type IntoIter = std::iter::Map<
+        std::iter::Enumerate<std::array::IntoIter<T, 6>>,
+        fn((usize, T)) -> (Direction, T),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unsafe {
+            self.0
+                .into_iter()
+                .enumerate()
+                .map(|(d, item)| (Direction::from_raw(d as u8), item))
+        }
+    }
+}
+
 impl<T> Index<Direction> for DirectionMap<T> {
     type Output = T;
 
@@ -749,6 +1010,11 @@ impl<T> OctantMap<T> {
         self.into_iter()
     }
 
+    /// Consumes the map, returning an iterator over its items in standard order.
+    pub fn into_values(self) -> std::array::IntoIter<T, 8> {
+        self.0.into_iter()
+    }
+
     /// Transforms the items in the map into a new map.
     pub fn map<U, F: FnMut(Octant, T) -> U>(self, mut f: F) -> OctantMap<U> {
         let [i0, i1, i2, i3, i4, i5, i6, i7] = self.0;
@@ -809,6 +1075,24 @@ impl<'a, T> IntoIterator for &'a OctantMap<T> {
     }
 }
 
+impl<T> IntoIterator for OctantMap<T> {
+    type Item = (Octant, T);
+
+    type IntoIter = std::iter::Map<
+        std::iter::Enumerate<std::array::IntoIter<T, 8>>,
+        fn((usize, T)) -> (Octant, T),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unsafe {
+            self.0
+                .into_iter()
+                .enumerate()
+                .map(|(d, item)| (Octant::from_raw(d as u8), item))
+        }
+    }
+}
+
 impl<T> Index<Octant> for OctantMap<T> {
     type Output = T;
 
@@ -822,3 +1106,61 @@ impl<T> IndexMut<Octant> for OctantMap<T> {
         self.get_mut(octant)
     }
 }
+
+/// Writes the members of a set of flags, separated by `|`, or `NONE` if the set is empty.
+fn write_flags<T: std::fmt::Debug>(
+    f: &mut std::fmt::Formatter<'_>,
+    flags: impl IntoIterator<Item = T>,
+) -> std::fmt::Result {
+    let mut members = flags.into_iter();
+
+    if let Some(first) = members.next() {
+        f.write_fmt(format_args!("{first:?}"))?;
+        for member in members {
+            f.write_fmt(format_args!(" | {member:?}"))?;
+        }
+        Ok(())
+    } else {
+        f.write_str("NONE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A value which can only be moved, not copied or cloned.
+    #[derive(Debug, PartialEq)]
+    struct Token(u32);
+
+    #[test]
+    fn direction_map_into_iter_moves_items() {
+        let map = DirectionMap::from_array([0, 1, 2, 3, 4, 5].map(Token));
+        let items = map.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(
+            items,
+            [
+                (Direction::LEFT, Token(0)),
+                (Direction::RIGHT, Token(1)),
+                (Direction::DOWN, Token(2)),
+                (Direction::UP, Token(3)),
+                (Direction::BACK, Token(4)),
+                (Direction::FRONT, Token(5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn octant_map_into_iter_moves_items() {
+        let map = OctantMap::from_array([0, 1, 2, 3, 4, 5, 6, 7].map(Token));
+        let mut count = 0;
+
+        for (octant, token) in map {
+            assert_eq!(token, Token(octant as u32));
+            count += 1;
+        }
+
+        assert_eq!(count, 8);
+    }
+}
@@ -1,12 +1,88 @@
+pub use crate::math::color::*;
 pub use crate::math::direction::*;
+use crate::physics::*;
 use bytemuck::*;
 pub use glam::*;
 use serde::*;
 use std::ops::*;
 
+/// Types for representing and blending colors.
+mod color;
+
 /// Types for distinguishing between various cardinal directions;
 mod direction;
 
+/// Allows for serializing world vectors as human-readable strings of voxel coordinates,
+/// via `#[serde(with = "worldvec_voxel")]`.
+pub mod worldvec_voxel;
+
+/// A value that may be smoothly interpolated.
+pub trait Lerp: Sized {
+    /// Interpolates between `self` and `other`. When `t = 0`, `self` is
+    /// returned, and when `t = 1`, `other` is returned.
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Vec2 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec2::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for Vec3 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec3::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for Vec3A {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec3A::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for Vec4 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec4::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for Quat {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, t)
+    }
+}
+
+impl Lerp for Rgba {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.lerp_oklab(other, t)
+    }
+}
+
+impl Lerp for Transform {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Transform::interpolate(self, other, t)
+    }
+}
+
+impl Lerp for WorldCoord {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        WorldCoord::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for WorldVec {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        WorldVec::lerp(*self, *other, t)
+    }
+}
+
 /// Describes a location and orientation in 3D space.
 #[derive(Copy, Clone, Serialize, Deserialize, Default, Debug, PartialEq)]
 pub struct Transform {
@@ -16,12 +92,120 @@ pub struct Transform {
     pub rotation: Quat,
 }
 
+/// Compares transforms by the distance between their positions, in voxels, and
+/// the angle between their rotations, in radians. Both use the same epsilon.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for Transform {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> Self::Epsilon {
+        WorldCoord::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.position.abs_diff_eq(&other.position, epsilon)
+            && self.rotation.angle_between(other.rotation) <= epsilon
+    }
+}
+
+/// Compares positions relatively, as with [`WorldVec`]. Rotations are always
+/// compared absolutely, since angles do not grow with distance from the origin.
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for Transform {
+    fn default_max_relative() -> Self::Epsilon {
+        WorldCoord::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.position
+            .relative_eq(&other.position, epsilon, max_relative)
+            && self.rotation.angle_between(other.rotation) <= epsilon
+    }
+}
+
 impl Transform {
     /// Creates a new transform with the specified position and rotation.
     pub fn new(position: WorldVec, rotation: Quat) -> Self {
         Self { position, rotation }
     }
 
+    /// Creates a transform at `position` whose front-facing direction points toward the
+    /// camera, keeping its upward direction as close as possible to `up`. If the camera
+    /// lies directly above or below `position`, the camera's own upward direction is used
+    /// instead, so that the result matches the top of the screen.
+    pub fn billboard(position: WorldVec, camera: &Transform, up: Vec3A) -> Self {
+        let forward = camera.position.displacement(position);
+        let up = if forward.cross(up).length_squared() <= 1e-6 * forward.length_squared() {
+            camera.rotation * Vec3A::Y
+        } else {
+            up
+        };
+
+        Self::new(position, look_rotation(forward, up))
+    }
+
+    /// Creates a transform at `position` which faces the camera as closely as possible
+    /// while only rotating around the normalized `axis`, which becomes the upward direction.
+    /// This is useful for objects such as trees and grass, which should remain upright.
+    /// If the camera lies along the axis, the transform faces opposite the camera's view.
+    pub fn billboard_axis(position: WorldVec, camera: &Transform, axis: Vec3A) -> Self {
+        let forward = camera.position.displacement(position);
+        let flat = forward - forward.dot(axis) * axis;
+        let flat = if 1e-6 < flat.length_squared() {
+            flat
+        } else {
+            -camera.forward_flat(axis)
+        };
+
+        Self::new(position, look_rotation(flat, axis))
+    }
+
+    /// Returns the normalized direction from this transform's position to `target`.
+    /// If `target` is equal to the position, [`Vec3A::ZERO`] is returned.
+    pub fn direction_to(&self, target: WorldVec) -> Vec3A {
+        target.displacement(self.position).normalize_or_zero()
+    }
+
+    /// Returns the distance from this transform's position to `target`, in voxels.
+    pub fn distance_to(&self, target: WorldVec) -> f32 {
+        target.displacement(self.position).length()
+    }
+
+    /// Returns the front-facing direction of this transform, projected onto the plane
+    /// perpendicular to the normalized `up` vector and renormalized. This is useful for
+    /// ground movement, since looking up or down does not change the result. If the
+    /// transform looks straight along `up`, the direction toward the top or bottom of the
+    /// view is used instead, so that the result always points where the view is facing.
+    pub fn forward_flat(&self, up: Vec3A) -> Vec3A {
+        let forward = self.look_direction();
+        let flatten = |x: Vec3A| {
+            let flat = x - x.dot(up) * up;
+            (1e-6 < flat.length_squared()).then(|| flat.normalize())
+        };
+
+        flatten(forward)
+            .or_else(|| flatten(-forward.dot(up).signum() * (self.rotation * Vec3A::Y)))
+            .unwrap_or(Vec3A::ZERO)
+    }
+
+    /// Creates a transform from a model matrix, where the matrix translation
+    /// is measured relative to `origin`. Any scale in the matrix is ignored.
+    pub fn from_matrix(m: Mat4, origin: WorldVec) -> Self {
+        let (_, rotation, translation) = m.to_scale_rotation_translation();
+        Self::new(origin + WorldVec::from(Vec3A::from(translation)), rotation)
+    }
+
+    /// Creates a transform at `position` whose front-facing direction points
+    /// toward `target`, keeping its upward direction as close as possible to `up`.
+    pub fn look_at(position: WorldVec, target: WorldVec, up: Vec3A) -> Self {
+        Self::new(position, look_rotation(target.displacement(position), up))
+    }
+
     /// Smoothly interpolates between the two given transforms. When `t = 0`,
     /// `a` is returned, and when `t = 1`, `b` is returned. `t` may be any finite
     /// floating point number.
@@ -32,6 +216,92 @@ impl Transform {
         )
     }
 
+    /// Interpolates between the positions of the two given transforms, keeping the
+    /// rotation of `a`. See [`Self::interpolate`] for the meaning of `t`.
+    pub fn interpolate_position(a: &Transform, b: &Transform, t: f32) -> Self {
+        Self::new(a.position.lerp(b.position, t), a.rotation)
+    }
+
+    /// Smoothly interpolates between the rotations of the two given transforms, keeping the
+    /// position of `a`. See [`Self::interpolate`] for the meaning of `t`.
+    pub fn interpolate_rotation(a: &Transform, b: &Transform, t: f32) -> Self {
+        Self::new(a.position, a.rotation.slerp(b.rotation, t))
+    }
+
+    /// Computes a stable key which identifies this transform after quantization, for use
+    /// in memoizing expensive computations that depend upon an approximate pose. The position
+    /// is snapped to a grid with cells of `position_step` voxels, and the rotation to steps of
+    /// roughly `angle_step` radians. By design, transforms which differ by less than a step
+    /// usually share the same key, although nearby transforms on opposite sides of a step
+    /// boundary will not. The key is consistent across runs and platforms.
+    pub fn quantized_key(&self, position_step: f32, angle_step: f32) -> u64 {
+        let position = self.position.as_i64vec3().as_dvec3()
+            / (WorldCoord::UNITS_PER_VOXEL as f64 * position_step as f64);
+
+        // Quaternion components change by about half of the rotation angle
+        let rotation = if self.rotation.w < 0.0 {
+            -self.rotation
+        } else {
+            self.rotation
+        };
+        let rotation = Vec4::from(rotation).as_dvec4() / (0.5 * angle_step as f64);
+
+        position
+            .floor()
+            .as_i64vec3()
+            .to_array()
+            .into_iter()
+            .chain(rotation.round().as_i64vec4().to_array())
+            .fold(0xcbf29ce484222325, |hash, x| {
+                x.to_le_bytes().into_iter().fold(hash, |hash, byte| {
+                    (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+                })
+            })
+    }
+
+    /// Returns the right-facing direction of this transform, perpendicular to both
+    /// [`Self::forward_flat`] and the normalized `up` vector.
+    pub fn right_flat(&self, up: Vec3A) -> Vec3A {
+        up.cross(self.forward_flat(up)).normalize_or_zero()
+    }
+
+    /// Creates a matrix which converts from points in this transform's coordinate
+    /// system to points in world space. The translation is measured relative to
+    /// `origin`, so that the matrix remains precise near the reference point.
+    pub fn to_matrix(&self, origin: WorldVec) -> Mat4 {
+        Mat4::from_rotation_translation(self.rotation, self.position.displacement(origin).into())
+    }
+
+    /// Converts many points from this transform's coordinate system to world space.
+    /// Each point is rotated by this transform and then offset from `self.position`.
+    /// Unlike [`Self::to_matrix`], this takes no `origin`: the results are [`WorldVec`]s,
+    /// which keep full precision everywhere, so there is no reference point to stay near.
+    pub fn transform_points(&self, points: &[Vec3A]) -> Vec<WorldVec> {
+        let mut result = vec![WorldVec::ZERO; points.len()];
+        self.transform_points_into(points, &mut result);
+        result
+    }
+
+    /// Converts many points from this transform's coordinate system to world space,
+    /// writing the results to `out` without allocating. The points are measured
+    /// relative to `self.position`, as in [`Self::transform_points`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` have different lengths.
+    pub fn transform_points_into(&self, points: &[Vec3A], out: &mut [WorldVec]) {
+        assert_eq!(
+            points.len(),
+            out.len(),
+            "Input and output slices had different lengths."
+        );
+
+        let rotation = Mat3A::from_quat(self.rotation);
+        for (point, result) in points.iter().zip(out) {
+            *result = self.position + WorldVec::from(rotation * *point);
+        }
+    }
+
     /// Creates a matrix which converts from points in the model coordinate
     /// system to points in this transform's coordinate space.
     pub fn view_model_matrix(&self, model: &Self) -> Mat4 {
@@ -49,7 +319,95 @@ impl Transform {
     }
 }
 
-/// Represents a position in world space.
+impl From<TransformRaw> for Transform {
+    fn from(value: TransformRaw) -> Self {
+        Self::new(cast(value.position), Quat::from_array(value.rotation))
+    }
+}
+
+/// A [`Transform`] with a fixed memory layout, suitable for copying
+/// directly into GPU buffers. Conversions between the two types are lossless.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable)]
+pub struct TransformRaw {
+    /// The position of the object in the world, measured in world units.
+    pub position: [i32; 3],
+    /// The rotation of the object, as quaternion components in `[x, y, z, w]` order.
+    pub rotation: [f32; 4],
+}
+
+impl From<Transform> for TransformRaw {
+    fn from(value: Transform) -> Self {
+        Self {
+            position: cast(value.position),
+            rotation: value.rotation.to_array(),
+        }
+    }
+}
+
+/// Creates a rotation whose front-facing direction (the local `+z` axis) points
+/// along `forward`, with the local `+y` axis as close as possible to `up`.
+/// If `forward` and `up` are parallel, an arbitrary perpendicular up vector is
+/// chosen instead. If `forward` is zero, the identity rotation is returned.
+pub fn look_rotation(forward: Vec3A, up: Vec3A) -> Quat {
+    let Some(z) = forward.try_normalize() else {
+        return Quat::IDENTITY;
+    };
+
+    let x = up
+        .cross(z)
+        .try_normalize()
+        .unwrap_or_else(|| z.any_orthonormal_vector());
+    let y = z.cross(x);
+
+    Quat::from_mat3a(&Mat3A::from_cols(x, y, z))
+}
+
+/// Rotates the direction `current` toward `target` by at most `max_radians`, along the
+/// great circle between them. If the angle between them is at most `max_radians`, `target`
+/// is returned, scaled to the length of `current`. The length of `current` is otherwise
+/// preserved. When the vectors are opposite, an arbitrary perpendicular axis is used.
+/// If either vector is zero, `current` is returned unchanged.
+pub fn rotate_towards(current: Vec3A, target: Vec3A, max_radians: f32) -> Vec3A {
+    let (Some(from), Some(to)) = (current.try_normalize(), target.try_normalize()) else {
+        return current;
+    };
+
+    let length = current.length();
+    if from.angle_between(to) <= max_radians {
+        return length * to;
+    } else if max_radians <= 0.0 {
+        return current;
+    }
+
+    let axis = from.cross(to);
+    let axis = if 1e-12 < axis.length_squared() {
+        axis.normalize()
+    } else {
+        from.any_orthonormal_vector()
+    };
+
+    length * (Quat::from_axis_angle(axis.into(), max_radians) * from)
+}
+
+/// Determines the point at which the ray crosses the plane, or `None` if the ray is parallel
+/// to the plane, points away from it, or would need to travel beyond its maximum distance.
+/// This does not consider voxel geometry, so it is suitable for targeting points on
+/// an imaginary surface, such as a ground plane.
+pub fn ray_plane(ray: &Ray, plane: &Plane) -> Option<WorldVec> {
+    let denominator = plane.normal.dot(ray.direction);
+    if denominator.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let distance = plane.signed_distance(ray.position) / -denominator;
+    (0.0..=ray.max_distance)
+        .contains(&distance)
+        .then(|| ray.position + WorldVec::from(distance * ray.direction))
+}
+
+/// Represents a position in world space. Equality and hashing both compare exact
+/// world units, so world vectors may be used as keys in hash maps.
 #[repr(C)]
 #[derive(Copy, Clone, Default, Serialize, Deserialize, Hash, PartialEq, Eq, Pod, Zeroable)]
 pub struct WorldVec {
@@ -69,11 +427,39 @@ impl WorldVec {
         z: WorldCoord::ZERO,
     };
 
+    /// Retrieves a representation of this value as a 64-bit integer vector, in world units.
+    /// This allows for intermediate arithmetic, such as summing many positions, which
+    /// would overflow the 32-bit representation.
+    pub fn as_i64vec3(self) -> I64Vec3 {
+        self.bits().as_i64vec3()
+    }
+
     /// Retrieves a representation of this value as an integer vector, in world units.
     pub fn bits(self) -> IVec3 {
         cast(self)
     }
 
+    /// Converts a 64-bit integer vector, in world units, to a world vector. Components
+    /// which lie outside of the representable range are clamped to the nearest bound.
+    pub fn from_i64vec3_clamped(v: I64Vec3) -> Self {
+        Self::from_bits(
+            v.clamp(
+                I64Vec3::splat(i32::MIN as i64),
+                I64Vec3::splat(i32::MAX as i64),
+            )
+            .as_ivec3(),
+        )
+    }
+
+    /// Splits this position into the index of the chunk that contains it, and the
+    /// coordinate of its voxel relative to the chunk's minimum corner. Chunk indices
+    /// are multiplied by `chunk_size` to obtain the chunk's voxel origin.
+    /// The engine's chunk size may be queried with [`Environment::chunk_size`].
+    pub fn chunk_coords(self, chunk_size: IVec3) -> (IVec3, IVec3) {
+        let voxel = self.voxel();
+        (voxel.div_euclid(chunk_size), voxel.rem_euclid(chunk_size))
+    }
+
     /// Obtains the displacement between this world position and another one
     /// in floating-point voxel units.
     pub fn displacement(self, other: Self) -> Vec3A {
@@ -102,6 +488,67 @@ impl WorldVec {
         self + Self::from(t * other.displacement(self))
     }
 
+    /// Interpolates along the polyline through `points`, where `t` is mapped across
+    /// the whole path by arc length. When `t <= 0`, the first point is returned, and
+    /// when `t >= 1`, the last point is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    pub fn lerp_path(points: &[WorldVec], t: f32) -> Self {
+        let (first, last) = match points {
+            [first, .., last] => (*first, *last),
+            [only] => return *only,
+            [] => panic!("Cannot interpolate along an empty path."),
+        };
+
+        if t <= 0.0 {
+            return first;
+        } else if 1.0 <= t {
+            return last;
+        }
+
+        let total = points
+            .windows(2)
+            .map(|x| x[1].displacement(x[0]).length())
+            .sum::<f32>();
+        let mut remaining = t * total;
+
+        for segment in points.windows(2) {
+            let length = segment[1].displacement(segment[0]).length();
+            if remaining <= length {
+                return if length > 0.0 {
+                    segment[0].lerp(segment[1], remaining / length)
+                } else {
+                    segment[0]
+                };
+            }
+            remaining -= length;
+        }
+
+        last
+    }
+
+    /// Gets the point halfway between this world position and another one,
+    /// rounded down to the nearest world unit.
+    pub fn midpoint(self, other: Self) -> Self {
+        Self {
+            x: self.x.midpoint(other.x),
+            y: self.y.midpoint(other.y),
+            z: self.z.midpoint(other.z),
+        }
+    }
+
+    /// Scales this vector by a floating-point factor in world units, rounding to the nearest
+    /// unit. Components which would fall outside of the representable range are saturated.
+    pub fn scale_f32(self, scale: f32) -> Self {
+        Self {
+            x: self.x.scale_f32(scale),
+            y: self.y.scale_f32(scale),
+            z: self.z.scale_f32(scale),
+        }
+    }
+
     /// Determines the voxel in which this world position resides.
     pub fn voxel(self) -> IVec3 {
         cast::<_, IVec3>(self) >> WorldCoord::LOG2_UNITS_PER_VOXEL
@@ -124,6 +571,22 @@ impl Sub for WorldVec {
     }
 }
 
+impl Mul<i32> for WorldVec {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        cast(cast::<_, IVec3>(self) * rhs)
+    }
+}
+
+impl Div<i32> for WorldVec {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        cast(cast::<_, IVec3>(self) / rhs)
+    }
+}
+
 impl AddAssign for WorldVec {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
@@ -136,6 +599,18 @@ impl SubAssign for WorldVec {
     }
 }
 
+impl MulAssign<i32> for WorldVec {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<i32> for WorldVec {
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
+
 impl From<Vec3A> for WorldVec {
     fn from(x: Vec3A) -> Self {
         let x_floor = x.floor();
@@ -146,12 +621,227 @@ impl From<Vec3A> for WorldVec {
     }
 }
 
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for WorldVec {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> Self::Epsilon {
+        WorldCoord::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for WorldVec {
+    fn default_max_relative() -> Self::Epsilon {
+        WorldCoord::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+    }
+}
+
 impl std::fmt::Debug for WorldVec {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!("{}", self.displacement(WorldVec::default())))
     }
 }
 
+/// An axis-aligned bounding box in world space.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct WorldAabb {
+    /// The minimum corner of the box.
+    pub min: WorldVec,
+    /// The maximum corner of the box.
+    pub max: WorldVec,
+}
+
+impl WorldAabb {
+    /// Creates a new bounding box from its minimum and maximum corners.
+    pub fn new(min: WorldVec, max: WorldVec) -> Self {
+        Self { min, max }
+    }
+
+    /// Whether the given point lies within this box, inclusive of its boundary.
+    pub fn contains(&self, point: WorldVec) -> bool {
+        self.min.bits().cmple(point.bits()).all() && point.bits().cmple(self.max.bits()).all()
+    }
+
+    /// Whether this box overlaps with another one, inclusive of their boundaries.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.bits().cmple(other.max.bits()).all()
+            && other.min.bits().cmple(self.max.bits()).all()
+    }
+
+    /// Gets the dimensions of this box, in floating-point voxel units.
+    pub fn size(&self) -> Vec3A {
+        self.max.displacement(self.min)
+    }
+}
+
+/// An axis-aligned bounding box using floating-point coordinates, relative to some origin.
+/// This is suited for transient, camera-local bounds that do not need the full precision
+/// of a [`WorldAabb`].
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct LocalAabb {
+    /// The minimum corner of the box.
+    pub min: Vec3A,
+    /// The maximum corner of the box.
+    pub max: Vec3A,
+}
+
+impl LocalAabb {
+    /// Creates a new bounding box from its minimum and maximum corners.
+    pub fn new(min: Vec3A, max: Vec3A) -> Self {
+        Self { min, max }
+    }
+
+    /// Converts a world-space bounding box into one relative to the given origin.
+    pub fn from_world(aabb: &WorldAabb, origin: WorldVec) -> Self {
+        Self {
+            min: aabb.min.displacement(origin),
+            max: aabb.max.displacement(origin),
+        }
+    }
+
+    /// Whether the given point lies within this box, inclusive of its boundary.
+    pub fn contains(&self, point: Vec3A) -> bool {
+        self.min.cmple(point).all() && point.cmple(self.max).all()
+    }
+
+    /// Grows this box so that it contains the given point.
+    pub fn expand(&mut self, point: Vec3A) {
+        self.min = self.min.min(point);
+        self.max = self.max.max(point);
+    }
+
+    /// Whether this box overlaps with another one, inclusive of their boundaries.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+    }
+
+    /// Gets the dimensions of this box.
+    pub fn size(&self) -> Vec3A {
+        self.max - self.min
+    }
+
+    /// Converts this box into world space, treating its coordinates as relative to the given origin.
+    pub fn to_world(&self, origin: WorldVec) -> WorldAabb {
+        WorldAabb {
+            min: origin + WorldVec::from(self.min),
+            max: origin + WorldVec::from(self.max),
+        }
+    }
+}
+
+/// An oriented bounding box in world space.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Obb {
+    /// The center of the box.
+    pub center: WorldVec,
+    /// Half of the box's size along each of its local axes, in voxels.
+    pub half_extents: Vec3A,
+    /// A quaternion which converts from the box's rotation space to world rotation space.
+    pub rotation: Quat,
+}
+
+impl Obb {
+    /// Creates a box centered on the transform's position, with the transform's rotation.
+    pub fn from_transform(transform: Transform, half_extents: Vec3A) -> Self {
+        Self {
+            center: transform.position,
+            half_extents,
+            rotation: transform.rotation,
+        }
+    }
+
+    /// Whether the given point lies within this box, inclusive of its boundary.
+    pub fn contains(&self, point: WorldVec) -> bool {
+        let local = self.rotation.inverse() * point.displacement(self.center);
+        local.abs().cmple(self.half_extents).all()
+    }
+
+    /// Determines the distance along the ray at which it first enters this box,
+    /// or `None` if the ray misses the box within its maximum distance. If the ray
+    /// begins inside of the box, `0.0` is returned.
+    pub fn intersects_ray(&self, ray: &Ray) -> Option<f32> {
+        let inverse = self.rotation.inverse();
+        let origin = inverse * ray.position.displacement(self.center);
+        let direction = inverse * ray.direction;
+
+        let mut near = 0.0f32;
+        let mut far = ray.max_distance;
+
+        for axis in 0..3 {
+            let (o, d, h) = (origin[axis], direction[axis], self.half_extents[axis]);
+            if d.abs() < f32::EPSILON {
+                if h < o.abs() {
+                    return None;
+                }
+            } else {
+                let a = (-h - o) / d;
+                let b = (h - o) / d;
+                near = near.max(a.min(b));
+                far = far.min(a.max(b));
+            }
+        }
+
+        (near <= far).then_some(near)
+    }
+}
+
+/// An infinite plane in world space.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Plane {
+    /// The unit vector perpendicular to the plane. Points on this side of the
+    /// plane have positive signed distances.
+    pub normal: Vec3A,
+    /// Any point which lies on the plane.
+    pub point: WorldVec,
+}
+
+impl Plane {
+    /// Creates a plane which passes through `point` and faces along `normal`.
+    /// The normal is normalized, and must not be zero.
+    pub fn new(point: WorldVec, normal: Vec3A) -> Self {
+        Self {
+            normal: normal.normalize(),
+            point,
+        }
+    }
+
+    /// Creates an upward-facing horizontal plane at the given height.
+    pub fn horizontal(y: WorldCoord) -> Self {
+        Self {
+            normal: Vec3A::Y,
+            point: WorldVec {
+                x: WorldCoord::ZERO,
+                y,
+                z: WorldCoord::ZERO,
+            },
+        }
+    }
+
+    /// Gets the distance from the plane to the given position in voxels, which is
+    /// positive on the side that the normal faces and negative on the other.
+    pub fn signed_distance(&self, position: WorldVec) -> f32 {
+        self.normal.dot(position.displacement(self.point))
+    }
+}
+
 /// A single coordinate within the world, representing a position or displacement.
 #[repr(transparent)]
 #[derive(Copy, Clone, Default, Serialize, Deserialize, Hash, PartialEq, Eq, Pod, Zeroable)]
@@ -201,6 +891,18 @@ impl WorldCoord {
         self + Self::from(t * other.displacement(self))
     }
 
+    /// Gets the point halfway between this world coordinate and another one,
+    /// rounded down to the nearest world unit.
+    pub fn midpoint(self, other: Self) -> Self {
+        Self(((self.0 as i64 + other.0 as i64) >> 1) as i32)
+    }
+
+    /// Scales this coordinate by a floating-point factor in world units, rounding to the nearest
+    /// unit. Results which would fall outside of the representable range are saturated.
+    pub fn scale_f32(self, scale: f32) -> Self {
+        Self((self.0 as f64 * scale as f64).round() as i32)
+    }
+
     /// Determines the voxel in which this world position resides.
     pub fn voxel(self) -> i32 {
         self.0 >> Self::LOG2_UNITS_PER_VOXEL
@@ -223,6 +925,22 @@ impl Sub for WorldCoord {
     }
 }
 
+impl Mul<i32> for WorldCoord {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Div<i32> for WorldCoord {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
 impl AddAssign for WorldCoord {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
@@ -235,6 +953,18 @@ impl SubAssign for WorldCoord {
     }
 }
 
+impl MulAssign<i32> for WorldCoord {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<i32> for WorldCoord {
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
+
 impl From<f32> for WorldCoord {
     fn from(x: f32) -> Self {
         let x_floor = x.floor();
@@ -245,6 +975,42 @@ impl From<f32> for WorldCoord {
     }
 }
 
+/// Compares coordinates by their distance in voxels. The default epsilon is a single world unit.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for WorldCoord {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> Self::Epsilon {
+        1.0 / Self::UNITS_PER_VOXEL as f32
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.displacement(*other).abs() <= epsilon
+    }
+}
+
+/// Compares coordinates by their distance in voxels, relative to their distance from the origin.
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for WorldCoord {
+    fn default_max_relative() -> Self::Epsilon {
+        f32::EPSILON
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let difference = self.displacement(*other).abs();
+        let largest = self
+            .displacement(Self::ZERO)
+            .abs()
+            .max(other.displacement(Self::ZERO).abs());
+        difference <= epsilon || difference <= largest * max_relative
+    }
+}
+
 impl std::fmt::Debug for WorldCoord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!("{}", self.0))
@@ -0,0 +1,62 @@
+use crate::math::*;
+use serde::de::*;
+
+/// Serializes a world vector as a string of comma-separated voxel coordinates,
+/// like `"12.5, 3.0, -4.25"`. The output is exact, so it round-trips without loss.
+pub fn serialize<S: Serializer>(value: &WorldVec, serializer: S) -> Result<S::Ok, S::Error> {
+    let [x, y, z] = voxel_coords(*value);
+    serializer.serialize_str(&format!("{x:?}, {y:?}, {z:?}"))
+}
+
+/// Deserializes a world vector from a string of comma-separated voxel coordinates.
+/// Coordinates are rounded to the nearest world unit.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<WorldVec, D::Error> {
+    parse(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+/// Parses a world vector from a string of comma-separated voxel coordinates.
+/// Coordinates are rounded to the nearest world unit.
+pub fn parse(value: &str) -> Result<WorldVec, String> {
+    let mut coords = value.split(',').map(|x| {
+        x.trim()
+            .parse::<f64>()
+            .map(|x| (x * WorldCoord::UNITS_PER_VOXEL as f64).round() as i32)
+            .map_err(|e| format!("Invalid coordinate '{}': {e}", x.trim()))
+    });
+
+    let result = match (coords.next(), coords.next(), coords.next(), coords.next()) {
+        (Some(x), Some(y), Some(z), None) => IVec3::new(x?, y?, z?),
+        _ => return Err(format!("Expected three coordinates; got '{value}'")),
+    };
+
+    Ok(WorldVec::from_bits(result))
+}
+
+/// Serializes world vectors as strings of comma-separated voxel coordinates,
+/// rounded to `DECIMALS` decimal places. May be used with
+/// `#[serde(with = "worldvec_voxel::Precision::<2>")]`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Precision<const DECIMALS: usize>;
+
+impl<const DECIMALS: usize> Precision<DECIMALS> {
+    /// Serializes a world vector as a string of comma-separated voxel coordinates,
+    /// rounded to `DECIMALS` decimal places.
+    pub fn serialize<S: Serializer>(value: &WorldVec, serializer: S) -> Result<S::Ok, S::Error> {
+        let [x, y, z] = voxel_coords(*value);
+        serializer.serialize_str(&format!("{x:.DECIMALS$}, {y:.DECIMALS$}, {z:.DECIMALS$}"))
+    }
+
+    /// Deserializes a world vector from a string of comma-separated voxel coordinates.
+    /// Coordinates are rounded to the nearest world unit.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<WorldVec, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+/// Gets the exact coordinates of a world vector in voxel units.
+fn voxel_coords(value: WorldVec) -> [f64; 3] {
+    value
+        .bits()
+        .to_array()
+        .map(|x| x as f64 / WorldCoord::UNITS_PER_VOXEL as f64)
+}
@@ -2,6 +2,89 @@ use crate::math::*;
 use serde::*;
 use wings::*;
 
+/// Controls how entities physically interact with one another.
+#[system_trait(host)]
+pub trait Collisions: 'static {
+    /// Sets how the entity with the given ID responds to contact with other objects.
+    /// The change takes effect at the start of the next physics step.
+    fn set_entity_collision(&self, id: u64, mode: CollisionMode);
+}
+
+/// Determines how an entity responds when it touches other objects.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum CollisionMode {
+    /// The entity does not interact with other objects at all.
+    Disabled,
+    /// The entity collides with and pushes against other objects.
+    #[default]
+    Solid,
+    /// Other objects pass through the entity, raising [`on::TriggerEnter`] when they do.
+    Trigger,
+}
+
+/// Describes the physical properties of the world around objects.
+#[system_trait(host)]
+pub trait Environment: 'static {
+    /// Gets the size of the engine's world chunks, in voxels.
+    fn chunk_size(&self) -> IVec3;
+
+    /// Computes the smallest displacement, in voxels, that moves the given box out of all solid
+    /// voxel geometry. Returns `None` if the box is already clear. This is the counterpart to
+    /// [`Self::is_region_clear`], and can be used to recover objects that were spawned or
+    /// teleported into walls.
+    fn depenetrate(&self, aabb: &WorldAabb) -> Option<Vec3A>;
+
+    /// Gets the acceleration due to gravity, in voxels per second squared.
+    fn gravity(&self) -> Vec3A;
+
+    /// Casts downward from the given position through the main voxel grid, and returns the
+    /// material index of the first voxel found within `max_distance` voxels. Returns `None`
+    /// if there is no ground within range. This is useful for choosing footstep sounds and
+    /// other surface effects.
+    fn ground_material(&self, position: WorldVec, max_distance: f32) -> Option<u32>;
+
+    /// Whether the given region contains no solid voxel geometry.
+    fn is_region_clear(&self, aabb: &WorldAabb) -> bool;
+
+    /// Whether the given world position lies inside of solid voxel geometry.
+    fn is_solid(&self, position: WorldVec) -> bool;
+
+    /// Determines the medium that fills the space at the given world position.
+    fn medium_at(&self, position: WorldVec) -> Medium;
+}
+
+/// Allows for pushing entities around the world. Forces and impulses are absolute, rather
+/// than mass-relative: they are measured in mass units times voxels per second (squared, for
+/// forces), where one mass unit is the mass of a single voxel. Heavier entities are therefore
+/// accelerated less by the same force.
+#[system_trait(host)]
+pub trait Forces: 'static {
+    /// Applies a continuous force, in mass units times voxels per second squared, to the center
+    /// of mass of the entity with the given ID. The force acts for the duration of the next
+    /// physics step only, so it should be reapplied every tick for as long as it persists.
+    fn apply_force(&self, id: u64, force: Vec3A);
+
+    /// Instantaneously applies an impulse, in mass units times voxels per second, to the entity
+    /// with the given ID. If a body-local `point` is supplied, the impulse is applied there and
+    /// may also cause the entity to rotate. Otherwise, it is applied at the center of mass.
+    fn apply_impulse(&self, id: u64, impulse: Vec3A, point: Option<Vec3A>);
+}
+
+/// Identifies a substance that objects may move through.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum Medium {
+    /// The default, empty medium.
+    #[default]
+    Air,
+    /// A medium that objects may swim through.
+    Water,
+    /// A medium defined by the game, identified by index.
+    Custom(u32),
+}
+
 /// Describes a ray that should be cast into the world.
 #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Ray {
@@ -29,6 +112,44 @@ pub struct RaycastHit {
     pub voxel: IVec3,
 }
 
+/// Describes the path that a ray took through the world.
+#[cfg(feature = "debug")]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RaycastDebug {
+    /// The intersection that the ray found, if any.
+    pub hit: Option<RaycastHit>,
+    /// Every voxel that the ray stepped through, in order of traversal.
+    pub traversed: Vec<IVec3>,
+}
+
+impl RaycastHit {
+    /// The distance, in voxels, that a reflected ray is pushed away from the surface it hit.
+    const REFLECTION_OFFSET: f32 = 1.0 / 64.0;
+
+    /// Creates a ray that continues `incoming` after it bounces off of the hit face.
+    /// The new ray begins just outside of the surface, travels in the reflected
+    /// direction, and may only travel the distance that `incoming` had remaining.
+    ///
+    /// Only hits against [`RaycastObject::World`] can be reflected, because the main voxel
+    /// grid is axis-aligned and so its face is a world-space normal. The face of an entity
+    /// hit is in the entity's local space, and the hit does not carry the entity's rotation,
+    /// so `None` is returned for [`RaycastObject::Entity`] hits.
+    pub fn reflect_ray(&self, incoming: &Ray) -> Option<Ray> {
+        if !matches!(self.object, RaycastObject::World {}) {
+            return None;
+        }
+
+        let normal = self.face.as_vec3a();
+        let offset = self.distance * incoming.direction + Self::REFLECTION_OFFSET * normal;
+
+        Some(Ray {
+            direction: incoming.direction - 2.0 * incoming.direction.dot(normal) * normal,
+            position: incoming.position + WorldVec::from(offset),
+            max_distance: (incoming.max_distance - self.distance).max(0.0),
+        })
+    }
+}
+
 /// An object that was hit during a ray query.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RaycastObject {
@@ -41,15 +162,104 @@ pub enum RaycastObject {
     World {},
 }
 
+/// Associates small pieces of user data with entities, such as their team or type.
+#[system_trait(host)]
+pub trait EntityTags: 'static {
+    /// Gets the tag associated with the entity, if any.
+    fn entity_tag(&self, id: u64) -> Option<Vec<u8>>;
+
+    /// Sets or clears the tag associated with the entity.
+    fn set_entity_tag(&self, id: u64, tag: Option<Vec<u8>>);
+}
+
 /// Determines the intersection between rays in the world and voxel objects.
 #[system_trait(host)]
 pub trait Raycaster: 'static {
     /// Casts a ray that can hit both entities and the main voxel grid.
     fn cast(&self, ray: &Ray) -> Option<RaycastHit>;
 
+    /// Casts a ray that can hit both entities and the main voxel grid. If an entity
+    /// is hit, its tag from [`EntityTags`] is returned alongside the hit, avoiding
+    /// a separate lookup.
+    fn cast_with_tag(&self, ray: &Ray) -> Option<(RaycastHit, Option<Vec<u8>>)>;
+
+    /// Casts many rays, which can hit both entities and the main voxel grid, within a cone.
+    /// The cone begins at `apex` and opens along the normalized `direction`, with `half_angle`
+    /// radians between its axis and its edge. The rays are distributed evenly over the cone
+    /// using a Fibonacci spiral, and each is returned alongside its direction.
+    fn cast_cone(
+        &self,
+        apex: WorldVec,
+        direction: Vec3A,
+        half_angle: f32,
+        ray_count: u32,
+        max_distance: f32,
+    ) -> Vec<(Vec3A, Option<RaycastHit>)>;
+
     /// Casts a ray that can hit entities but ignores the main voxel grid.
     fn cast_entities(&self, ray: &Ray) -> Option<RaycastHit>;
 
+    /// Casts a ray that can only hit the entity with the given ID, ignoring all other
+    /// entities and the main voxel grid. This is much cheaper than [`Self::cast_entities`]
+    /// when testing against a single known target. Returns `None` if the entity does not exist.
+    fn cast_entity(&self, ray: &Ray, id: u64) -> Option<RaycastHit>;
+
     /// Casts a ray that can hit the main voxel grid but ignores entities.
     fn cast_world(&self, ray: &Ray) -> Option<RaycastHit>;
 }
+
+impl dyn Raycaster {
+    /// Finds the ground beneath a standing position, so that characters stay attached to
+    /// the ground when walking over small steps and slopes. Rays are cast downward through
+    /// the main voxel grid from the center and from four points `radius` voxels away, and
+    /// the highest ground is used. If the ground lies within `max_snap` voxels above or below
+    /// `position`, the position on the ground is returned. Otherwise, `None` is returned
+    /// to indicate that the position is airborne.
+    pub fn snap_to_ground(
+        &self,
+        position: WorldVec,
+        max_snap: f32,
+        radius: f32,
+    ) -> Option<WorldVec> {
+        let up = Direction::UP.as_vec3a();
+        let start = position + WorldVec::from(max_snap * up);
+
+        [Vec3A::ZERO, Vec3A::X, Vec3A::NEG_X, Vec3A::Z, Vec3A::NEG_Z]
+            .into_iter()
+            .filter_map(|offset| {
+                self.cast_world(&Ray {
+                    direction: -up,
+                    position: start + WorldVec::from(radius * offset),
+                    max_distance: 2.0 * max_snap,
+                })
+            })
+            .map(|hit| hit.distance)
+            .min_by(f32::total_cmp)
+            .map(|distance| start - WorldVec::from(distance * up))
+    }
+}
+
+/// Provides diagnostic information about ray queries.
+#[cfg(feature = "debug")]
+#[system_trait(host)]
+pub trait RaycastDebugger: 'static {
+    /// Casts a ray that can hit both entities and the main voxel grid,
+    /// recording each voxel that was traversed along the way.
+    fn cast_debug(&self, ray: &Ray) -> RaycastDebug;
+}
+
+/// The set of events that this module raises.
+pub mod on {
+    use super::*;
+
+    /// Raised when an object begins overlapping an entity whose
+    /// collision mode is [`CollisionMode::Trigger`].
+    #[derive(Clone, Debug)]
+    #[export_type]
+    pub struct TriggerEnter {
+        /// The ID of the trigger entity.
+        pub entity: u64,
+        /// The object that entered the trigger.
+        pub other: RaycastObject,
+    }
+}
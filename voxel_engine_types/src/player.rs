@@ -1,3 +1,4 @@
+use crate::asset::*;
 use crate::math::*;
 use serde::*;
 use wings::*;
@@ -8,6 +9,25 @@ use wings::*;
 /// Only available on the [`Client`](crate::Client).
 #[system_trait(host)]
 pub trait Player: 'static {
+    /// Gets the tool that the player is currently holding, if any.
+    fn active_tool(&self) -> Option<ToolId>;
+
+    /// Rotates the player's view by the given amounts, in radians, as if the user had moved the
+    /// mouse. Positive yaw turns to the right, and positive pitch looks upward. The input is
+    /// combined with mouse and controller look input during the next frame, so it is subject
+    /// to the same pitch limits and blends naturally with the user's own movements. This has
+    /// no effect while the built-in controls are disabled by [`Self::set_movement_enabled`].
+    fn add_look_input(&mut self, yaw_delta: f32, pitch_delta: f32);
+
+    /// Gets the transform of the camera used to render the world. In first-person mode, this
+    /// matches [`Self::get_transform`]. In third-person mode, it is the camera's actual position
+    /// after being pulled in to avoid passing through voxels.
+    fn camera_transform(&self) -> Transform;
+
+    /// Deletes every voxel covered by the brush centered at the given world position.
+    /// The whole edit is applied atomically at the end of the current frame.
+    fn delete_brush(&self, center: IVec3, brush: BrushShape);
+
     /// Deletes some voxels at the given world position.
     /// The edit occurs at the end of the current frame.
     fn delete_voxels_at(&self, position: IVec3);
@@ -18,16 +38,103 @@ pub trait Player: 'static {
     /// Gets the player's current transform.
     fn get_transform(&self) -> Transform;
 
+    /// Gets the maximum distance, in voxels, at which the player can edit or drag objects.
+    fn interaction_reach(&self) -> f32;
+
+    /// Returns whether the engine's built-in movement and look controls are active.
+    fn movement_enabled(&self) -> bool;
+
+    /// Lists the kinds of physics object that may be passed to [`Self::spawn_physics_object`].
+    fn physics_kinds(&self) -> Vec<PhysicsKindInfo>;
+
+    /// Registers a new tool that the player may hold, and returns its ID.
+    fn register_tool(&mut self, descriptor: ToolDescriptor) -> ToolId;
+
+    /// Places voxels throughout the brush centered at the given world position,
+    /// using the shape with the given index; see [`Self::voxel_shapes`] for valid indices.
+    /// The whole edit is applied atomically at the end of the current frame.
+    fn place_brush(&self, center: IVec3, brush: BrushShape, shape_index: u32);
+
     /// Places some voxels at the given world position. The voxel shape
-    /// changes based upon index.
+    /// changes based upon index; see [`Self::voxel_shapes`] for valid indices.
     /// The edit occurs at the end of the current frame.
     fn place_voxels_at(&self, position: IVec3, shape_index: u32);
 
+    /// Sets the tool that the player is holding, or clears it if `None` is given. The engine
+    /// displays the tool's model and reticle while it is active. All systems share this slot,
+    /// so the tool may be changed by another system at any time.
+    fn set_active_tool(&mut self, tool: Option<ToolId>);
+
+    /// Sets how the camera is positioned relative to the player.
+    fn set_camera_mode(&mut self, mode: CameraMode);
+
+    /// Sets whether the player is unaffected by gravity, so that they may fly freely while
+    /// keeping the usual look controls. When flying is disabled, the player falls normally.
+    fn set_fly(&mut self, enabled: bool);
+
+    /// Sets the maximum distance, in voxels, at which the player can edit or drag objects.
+    fn set_interaction_reach(&mut self, voxels: f32);
+
+    /// Sets whether the engine's built-in movement and look controls are active.
+    /// While disabled, the player's transform only changes via [`Self::set_transform`].
+    /// Re-enabling movement resumes control from the current transform without snapping.
+    fn set_movement_enabled(&mut self, enabled: bool);
+
+    /// Sets whether the player passes through voxels and entities without colliding.
+    /// When noclip is disabled while the player is inside solid geometry, the player is
+    /// moved to the nearest clear position and snapped to the ground below it.
+    fn set_noclip(&mut self, enabled: bool);
+
     /// Sets the player's current transform.
     fn set_transform(&mut self, transform: Transform);
 
     /// Spawns a physics object for testing at the end of the current frame.
     fn spawn_physics_object(&self, position: WorldVec, kind_index: u32);
+
+    /// Lists the voxel shapes that may be passed to [`Self::place_voxels_at`].
+    fn voxel_shapes(&self) -> Vec<VoxelShapeInfo>;
+
+    /// Projects a world position onto the screen using the player's camera, returning
+    /// its coordinates in pixels relative to the top-left corner of the window.
+    /// Returns `None` if the position lies behind the camera's near plane.
+    /// Positions that are in front of the camera but outside the viewport are still returned.
+    fn world_to_screen(&self, position: WorldVec) -> Option<Vec2>;
+}
+
+/// Determines the region of voxels affected by a brush edit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BrushShape {
+    /// An axis-aligned cube of voxels, extending `radius` voxels from the center along each axis.
+    Cube {
+        /// The distance from the center to each face of the cube.
+        radius: u32,
+    },
+    /// A straight line of voxels from the center to another position, inclusive of both ends.
+    Line {
+        /// The voxel position at which the line ends.
+        to: IVec3,
+    },
+    /// All voxels whose centers lie within `radius` voxels of the center.
+    Sphere {
+        /// The radius of the sphere.
+        radius: u32,
+    },
+}
+
+/// Determines how the camera is positioned relative to the player.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum CameraMode {
+    /// The camera is located at the player's eyes.
+    #[default]
+    FirstPerson,
+    /// The camera follows behind the player. If voxels lie between the player and
+    /// the camera, the engine moves the camera closer so that the view is not obstructed.
+    ThirdPerson {
+        /// The distance behind the player at which the camera is placed, in voxels.
+        distance: f32,
+        /// An additional offset applied to the camera, in the player's local space.
+        offset: Vec3A,
+    },
 }
 
 /// Describes a drag operation on an entity.
@@ -40,3 +147,183 @@ pub struct DragEntity {
     /// The position to which the object should be dragged.
     pub target_position: WorldVec,
 }
+
+/// Describes a kind of physics object that may be spawned.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PhysicsKindInfo {
+    /// The index that identifies this kind.
+    pub index: u32,
+    /// The human-readable name of this kind.
+    pub name: String,
+}
+
+/// Describes a tool that the player may hold.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ToolDescriptor {
+    /// An [`Asset::Mesh`] to draw in the player's hand while the tool is active.
+    pub model: Option<AssetId>,
+    /// The human-readable name of this tool.
+    pub name: String,
+    /// An [`Asset::Image`] to draw at the center of the screen while the tool is active.
+    /// If `None`, the engine's default reticle is used.
+    pub reticle: Option<AssetId>,
+}
+
+/// Identifies a tool registered with [`Player::register_tool`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ToolId(u64);
+
+impl From<u64> for ToolId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ToolId> for u64 {
+    fn from(value: ToolId) -> Self {
+        value.0
+    }
+}
+
+/// Describes a voxel shape that may be placed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct VoxelShapeInfo {
+    /// The index that identifies this shape.
+    pub index: u32,
+    /// The human-readable name of this shape.
+    pub name: String,
+}
+
+/// Smooths corrections to a client-side predicted transform. When the server sends an
+/// authoritative transform, the difference between the displayed transform and the corrected
+/// prediction is applied as an offset, which fades out over a few frames rather than snapping.
+/// The offset is applied on top of each new prediction, so the displayed transform keeps
+/// following the player while the correction is hidden.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Reconciler {
+    /// The number of frames that have passed since the last correction.
+    frames: u32,
+    /// The displayed position minus the authoritative position at the time of the last correction.
+    position_offset: WorldVec,
+    /// The most recent predicted transform.
+    predicted: Transform,
+    /// The rotation from the authoritative rotation to the displayed
+    /// rotation at the time of the last correction.
+    rotation_offset: Quat,
+    /// The number of frames over which corrections are smoothed.
+    smoothing_frames: u32,
+}
+
+impl Reconciler {
+    /// Creates a new reconciler starting at the given transform, which smooths
+    /// corrections over `smoothing_frames` frames.
+    pub fn new(initial: Transform, smoothing_frames: u32) -> Self {
+        Self {
+            frames: smoothing_frames,
+            position_offset: WorldVec::ZERO,
+            predicted: initial,
+            rotation_offset: Quat::IDENTITY,
+            smoothing_frames,
+        }
+    }
+
+    /// Applies an authoritative transform from the server. The predicted transform is
+    /// replaced, and the difference between it and the displayed transform begins fading out.
+    pub fn correct(&mut self, authoritative: Transform) {
+        let displayed = self.current();
+        self.position_offset = displayed.position - authoritative.position;
+        self.rotation_offset = displayed.rotation * authoritative.rotation.inverse();
+        self.predicted = authoritative;
+        self.frames = 0;
+    }
+
+    /// Gets the smoothed transform that should be displayed this frame.
+    pub fn current(&self) -> Transform {
+        if self.frames < self.smoothing_frames {
+            let remaining = 1.0 - self.frames as f32 / self.smoothing_frames as f32;
+            Transform::new(
+                self.predicted.position + WorldVec::ZERO.lerp(self.position_offset, remaining),
+                Quat::IDENTITY.slerp(self.rotation_offset, remaining) * self.predicted.rotation,
+            )
+        } else {
+            self.predicted
+        }
+    }
+
+    /// Advances by one frame, using the given transform as the latest client-side prediction.
+    pub fn predict(&mut self, predicted: Transform) {
+        self.predicted = predicted;
+        self.frames = self.frames.saturating_add(1).min(self.smoothing_frames);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates an unrotated transform at the given position.
+    fn at(x: f32) -> Transform {
+        Transform::new(WorldVec::from(Vec3A::new(x, 0.0, 0.0)), Quat::IDENTITY)
+    }
+
+    /// Gets the x-coordinate of the given transform, in voxels.
+    fn x(transform: Transform) -> f32 {
+        transform.position.displacement(WorldVec::ZERO).x
+    }
+
+    #[test]
+    fn stationary_correction() {
+        let mut reconciler = Reconciler::new(at(0.0), 4);
+        reconciler.correct(at(4.0));
+        assert!((x(reconciler.current()) - 0.0).abs() < 1e-3);
+
+        reconciler.predict(at(4.0));
+        assert!((x(reconciler.current()) - 1.0).abs() < 1e-3);
+
+        for _ in 0..3 {
+            reconciler.predict(at(4.0));
+        }
+        assert_eq!(reconciler.current(), at(4.0));
+    }
+
+    #[test]
+    fn moving_correction() {
+        let mut reconciler = Reconciler::new(at(0.0), 4);
+        for frame in 1..=10 {
+            reconciler.predict(at(frame as f32));
+        }
+
+        // The server reports that the player is one voxel behind the prediction.
+        reconciler.correct(at(9.0));
+        assert!((x(reconciler.current()) - 10.0).abs() < 1e-3);
+
+        // While the player keeps moving, the display must keep moving forward
+        // and never be pulled back toward the pose at the time of correction.
+        let mut previous = x(reconciler.current());
+        for frame in 1..=4 {
+            reconciler.predict(at(9.0 + frame as f32));
+            let current = x(reconciler.current());
+            let expected = 9.0 + frame as f32 + (1.0 - frame as f32 / 4.0);
+            assert!((current - expected).abs() < 1e-3);
+            assert!(current > previous);
+            previous = current;
+        }
+
+        assert_eq!(reconciler.current(), at(13.0));
+    }
+
+    #[test]
+    fn rotation_correction() {
+        let mut reconciler = Reconciler::new(Transform::default(), 2);
+        let turned = Transform::new(WorldVec::ZERO, Quat::from_rotation_y(1.0));
+        reconciler.correct(turned);
+        assert!(reconciler.current().rotation.angle_between(Quat::IDENTITY) < 1e-3);
+
+        reconciler.predict(turned);
+        let halfway = reconciler.current().rotation;
+        assert!((halfway.angle_between(Quat::IDENTITY) - 0.5).abs() < 1e-3);
+
+        reconciler.predict(turned);
+        assert_eq!(reconciler.current(), turned);
+    }
+}
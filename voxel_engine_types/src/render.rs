@@ -0,0 +1,36 @@
+use crate::asset::*;
+use crate::math::*;
+use serde::*;
+use wings::*;
+
+/// Allows for placing static, unlit meshes in the world.
+/// Only available on the [`Client`](crate::Client).
+#[system_trait(host)]
+pub trait MeshRenderer: 'static {
+    /// Removes a mesh instance from the world. Does nothing
+    /// if the instance was already removed.
+    fn despawn_mesh(&self, id: MeshInstanceId);
+
+    /// Sets the transform of an existing mesh instance.
+    fn set_mesh_transform(&self, id: MeshInstanceId, transform: Transform);
+
+    /// Places a new instance of the given [`Asset::Mesh`] in the world,
+    /// and returns a handle that may be used to modify it later.
+    fn spawn_mesh(&self, id: AssetId, transform: Transform) -> MeshInstanceId;
+}
+
+/// Identifies a mesh that has been spawned in the world.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct MeshInstanceId(u64);
+
+impl From<u64> for MeshInstanceId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<MeshInstanceId> for u64 {
+    fn from(value: MeshInstanceId) -> Self {
+        value.0
+    }
+}
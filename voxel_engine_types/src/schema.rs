@@ -0,0 +1,995 @@
+use crate::asset::*;
+use crate::input::*;
+use crate::math::*;
+use crate::physics::*;
+use serde::de::value::*;
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::*;
+use std::cell::*;
+use std::fmt::{Display, Formatter};
+
+/// Describes the serialized shape of a type that is marshaled between the host and guests.
+/// Every host trait exchanges data using `serde`, so a guest built against a different
+/// version of this crate could otherwise misinterpret data without any error. Comparing
+/// schemas at load time allows such mismatches to be detected and reported.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TypeSchema {
+    /// An enum, described by its name and its variants in declaration order.
+    /// Unit variants have a [`TypeSchema::Unit`] payload, newtype variants a
+    /// [`TypeSchema::Newtype`], tuple variants a [`TypeSchema::Tuple`], and struct
+    /// variants a [`TypeSchema::Struct`], each named after the variant.
+    Enum {
+        /// The name of the enum.
+        name: String,
+        /// The names and payloads of the variants.
+        variants: Vec<(String, TypeSchema)>,
+    },
+    /// A map from keys to values.
+    Map {
+        /// The type of the keys.
+        key: Box<TypeSchema>,
+        /// The type of the values.
+        value: Box<TypeSchema>,
+    },
+    /// A struct which wraps a single unnamed value.
+    Newtype {
+        /// The name of the struct.
+        name: String,
+        /// The type of the wrapped value.
+        inner: Box<TypeSchema>,
+    },
+    /// A value which may or may not be present.
+    Option {
+        /// The type of the value.
+        inner: Box<TypeSchema>,
+    },
+    /// A primitive value, such as an integer or string.
+    Primitive {
+        /// The name of the primitive type.
+        name: String,
+    },
+    /// A variable-length sequence of values.
+    Sequence {
+        /// The type of each element.
+        element: Box<TypeSchema>,
+    },
+    /// A struct with named fields.
+    Struct {
+        /// The name of the struct.
+        name: String,
+        /// The names and types of the fields in declaration order.
+        fields: Vec<(String, TypeSchema)>,
+    },
+    /// A fixed-length tuple or tuple struct.
+    Tuple {
+        /// The name of the tuple struct, or an empty string for anonymous tuples.
+        name: String,
+        /// The types of the elements.
+        elements: Vec<TypeSchema>,
+    },
+    /// A type without any data.
+    Unit,
+}
+
+impl TypeSchema {
+    /// Determines the schema of the given type by tracing its [`Deserialize`] implementation.
+    ///
+    /// # Panics
+    ///
+    /// If the type requires a self-describing format to deserialize.
+    pub fn of<T: DeserializeOwned>() -> Self {
+        // Each pass deserializes one variant of every enum that it reaches,
+        // so the type is traced repeatedly until every variant has been seen.
+        // Variants that no pass can reach are treated as units.
+        let state = RefCell::new(TraceState::default());
+        let mut explored = 0;
+        loop {
+            let mut schema = None;
+            T::deserialize(Tracer {
+                schema: &mut schema,
+                state: &state,
+            })
+            .unwrap_or_else(|error| {
+                panic!("Failed to trace {}: {error}", std::any::type_name::<T>())
+            });
+
+            let state = state.borrow();
+            let previous = std::mem::replace(&mut explored, state.explored_variants());
+            if explored == previous || state.enums.iter().all(TracedEnum::explored) {
+                return state.resolve(schema.unwrap_or(Self::Unit), &mut Vec::new());
+            }
+        }
+    }
+
+    /// The schemas of the most important types marshaled across the host boundary.
+    /// The host may compare these against the guest's copies to reject incompatible mods.
+    pub fn marshaled_types() -> Vec<Self> {
+        vec![
+            Self::of::<ActionDescriptor<Analog>>(),
+            Self::of::<ActionDescriptor<Digital>>(),
+            Self::of::<Asset>(),
+            Self::of::<RaycastHit>(),
+            Self::of::<Transform>(),
+        ]
+    }
+
+    /// Checks whether data serialized according to `other` may be read using this schema.
+    /// Returns a description of the first difference if the schemas are incompatible.
+    pub fn check_compatible(&self, other: &Self) -> Result<(), SchemaMismatch> {
+        self.find_mismatch(other, self.name())
+    }
+
+    /// Whether this schema refers to the enum with the given name.
+    fn contains_enum(&self, enum_name: &str) -> bool {
+        match self {
+            Self::Enum { name, variants } => {
+                name == enum_name || variants.iter().any(|x| x.1.contains_enum(enum_name))
+            }
+            Self::Map { key, value } => {
+                key.contains_enum(enum_name) || value.contains_enum(enum_name)
+            }
+            Self::Newtype { inner, .. } | Self::Option { inner } => inner.contains_enum(enum_name),
+            Self::Sequence { element } => element.contains_enum(enum_name),
+            Self::Struct { fields, .. } => fields.iter().any(|x| x.1.contains_enum(enum_name)),
+            Self::Tuple { elements, .. } => elements.iter().any(|x| x.contains_enum(enum_name)),
+            Self::Primitive { .. } | Self::Unit => false,
+        }
+    }
+
+    /// Gets the name of this type, for use in error messages.
+    fn name(&self) -> String {
+        match self {
+            Self::Enum { name, .. }
+            | Self::Newtype { name, .. }
+            | Self::Primitive { name }
+            | Self::Struct { name, .. }
+            | Self::Tuple { name, .. } => name.clone(),
+            Self::Map { .. } => "map".to_string(),
+            Self::Option { .. } => "option".to_string(),
+            Self::Sequence { .. } => "sequence".to_string(),
+            Self::Unit => "()".to_string(),
+        }
+    }
+
+    /// Recursively compares two schemas, tracking the path to the current value.
+    fn find_mismatch(&self, other: &Self, path: String) -> Result<(), SchemaMismatch> {
+        match (self, other) {
+            (
+                Self::Enum {
+                    name: a,
+                    variants: variants_a,
+                },
+                Self::Enum {
+                    name: b,
+                    variants: variants_b,
+                },
+            ) if a == b
+                && variants_a.len() == variants_b.len()
+                && variants_a.iter().zip(variants_b).all(|(x, y)| x.0 == y.0) =>
+            {
+                for ((variant, x), (_, y)) in variants_a.iter().zip(variants_b) {
+                    x.find_mismatch(y, format!("{path}::{variant}"))?;
+                }
+                Ok(())
+            }
+            (
+                Self::Struct {
+                    name: a,
+                    fields: fields_a,
+                },
+                Self::Struct {
+                    name: b,
+                    fields: fields_b,
+                },
+            ) if a == b
+                && fields_a.len() == fields_b.len()
+                && fields_a.iter().zip(fields_b).all(|(x, y)| x.0 == y.0) =>
+            {
+                for ((field, x), (_, y)) in fields_a.iter().zip(fields_b) {
+                    x.find_mismatch(y, format!("{path}.{field}"))?;
+                }
+                Ok(())
+            }
+            (
+                Self::Tuple {
+                    name: a,
+                    elements: elements_a,
+                },
+                Self::Tuple {
+                    name: b,
+                    elements: elements_b,
+                },
+            ) if a == b && elements_a.len() == elements_b.len() => {
+                for (i, (x, y)) in elements_a.iter().zip(elements_b).enumerate() {
+                    x.find_mismatch(y, format!("{path}.{i}"))?;
+                }
+                Ok(())
+            }
+            (Self::Newtype { name: a, inner: x }, Self::Newtype { name: b, inner: y })
+                if a == b =>
+            {
+                x.find_mismatch(y, format!("{path}.0"))
+            }
+            (Self::Option { inner: x }, Self::Option { inner: y }) => x.find_mismatch(y, path),
+            (Self::Sequence { element: x }, Self::Sequence { element: y }) => {
+                x.find_mismatch(y, format!("{path}[]"))
+            }
+            (Self::Map { key: xk, value: xv }, Self::Map { key: yk, value: yv }) => {
+                xk.find_mismatch(yk, format!("{path}{{key}}"))?;
+                xv.find_mismatch(yv, format!("{path}{{value}}"))
+            }
+            _ if self == other => Ok(()),
+            _ => Err(SchemaMismatch {
+                expected: self.to_string(),
+                found: other.to_string(),
+                path,
+            }),
+        }
+    }
+}
+
+impl Display for TypeSchema {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Enum { name, variants } => {
+                let names = variants.iter().map(|x| x.0.as_str()).collect::<Vec<_>>();
+                f.write_fmt(format_args!("enum {name} {{ {} }}", names.join(", ")))
+            }
+            Self::Map { key, value } => f.write_fmt(format_args!("map<{key}, {value}>")),
+            Self::Newtype { name, inner } => f.write_fmt(format_args!("{name}({inner})")),
+            Self::Option { inner } => f.write_fmt(format_args!("option<{inner}>")),
+            Self::Primitive { name } => f.write_str(name),
+            Self::Sequence { element } => f.write_fmt(format_args!("sequence<{element}>")),
+            Self::Struct { name, fields } => {
+                let names = fields.iter().map(|x| x.0.as_str()).collect::<Vec<_>>();
+                f.write_fmt(format_args!("struct {name} {{ {} }}", names.join(", ")))
+            }
+            Self::Tuple { name, elements } => {
+                let names = elements.iter().map(Self::name).collect::<Vec<_>>();
+                f.write_fmt(format_args!("{name}({})", names.join(", ")))
+            }
+            Self::Unit => f.write_str("()"),
+        }
+    }
+}
+
+/// Describes the first difference found between two incompatible [`TypeSchema`]s.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SchemaMismatch {
+    /// A description of the schema that was expected.
+    pub expected: String,
+    /// A description of the schema that was found instead.
+    pub found: String,
+    /// The path to the mismatched value, starting from the outermost type.
+    pub path: String,
+}
+
+impl Display for SchemaMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "Incompatible type at {}: expected {}, found {}",
+            self.path, self.expected, self.found
+        ))
+    }
+}
+
+impl std::error::Error for SchemaMismatch {}
+
+/// Records the variants of each enum discovered while tracing a type.
+#[derive(Default)]
+struct TraceState {
+    /// The enums that have been reached, in the order that they were first reached.
+    enums: Vec<TracedEnum>,
+    /// The enum variants currently being traced, from outermost to innermost,
+    /// as indices into `enums` and the variant list.
+    stack: Vec<(usize, usize)>,
+}
+
+impl TraceState {
+    /// Counts the variants, across all enums, that have been traced.
+    fn explored_variants(&self) -> usize {
+        self.enums
+            .iter()
+            .flat_map(|x| &x.variants)
+            .filter(|x| x.1.is_some())
+            .count()
+    }
+
+    /// Chooses which variant of the given enum to trace next. Unexplored variants are
+    /// preferred. Otherwise, a variant which leads to an unexplored enum is chosen, so
+    /// that every pass makes progress. Variants that are already being traced, or that
+    /// contain the enum itself, are avoided so that recursive types terminate.
+    fn choose_variant(&self, index: usize) -> usize {
+        let traced = &self.enums[index];
+        let available = |variant: &usize| !self.stack.contains(&(index, *variant));
+
+        if let Some(variant) = (0..traced.variants.len())
+            .filter(available)
+            .find(|x| traced.variants[*x].1.is_none())
+        {
+            return variant;
+        }
+
+        let leads_to_unexplored = self
+            .enums
+            .iter()
+            .filter(|x| !x.explored())
+            .find_map(|x| {
+                let mut parent = x.parent;
+                while let Some((outer, variant)) = parent {
+                    if outer == index {
+                        return Some(variant);
+                    }
+                    parent = self.enums[outer].parent;
+                }
+                None
+            })
+            .filter(available);
+
+        leads_to_unexplored
+            .or_else(|| {
+                (0..traced.variants.len()).filter(available).find(|x| {
+                    traced.variants[*x]
+                        .1
+                        .as_ref()
+                        .is_some_and(|payload| !payload.contains_enum(traced.name))
+                })
+            })
+            .unwrap_or(0)
+    }
+
+    /// Replaces the placeholder enums within a schema with their traced variants.
+    /// `visiting` holds the enums currently being resolved, so that recursive
+    /// types are left as placeholders rather than expanded forever.
+    fn resolve(&self, schema: TypeSchema, visiting: &mut Vec<String>) -> TypeSchema {
+        match schema {
+            TypeSchema::Enum { name, variants } => {
+                let Some(traced) = self.enums.iter().find(|x| x.name == name) else {
+                    return TypeSchema::Enum { name, variants };
+                };
+
+                if visiting.contains(&name) {
+                    return TypeSchema::Enum { name, variants };
+                }
+
+                visiting.push(name.clone());
+                let variants = traced
+                    .variants
+                    .iter()
+                    .map(|(variant, payload)| {
+                        let payload = payload.clone().unwrap_or(TypeSchema::Unit);
+                        (variant.to_string(), self.resolve(payload, visiting))
+                    })
+                    .collect();
+                visiting.pop();
+                TypeSchema::Enum { name, variants }
+            }
+            TypeSchema::Map { key, value } => TypeSchema::Map {
+                key: Box::new(self.resolve(*key, visiting)),
+                value: Box::new(self.resolve(*value, visiting)),
+            },
+            TypeSchema::Newtype { name, inner } => TypeSchema::Newtype {
+                name,
+                inner: Box::new(self.resolve(*inner, visiting)),
+            },
+            TypeSchema::Option { inner } => TypeSchema::Option {
+                inner: Box::new(self.resolve(*inner, visiting)),
+            },
+            TypeSchema::Sequence { element } => TypeSchema::Sequence {
+                element: Box::new(self.resolve(*element, visiting)),
+            },
+            TypeSchema::Struct { name, fields } => TypeSchema::Struct {
+                name,
+                fields: fields
+                    .into_iter()
+                    .map(|(field, x)| (field, self.resolve(x, visiting)))
+                    .collect(),
+            },
+            TypeSchema::Tuple { name, elements } => TypeSchema::Tuple {
+                name,
+                elements: elements
+                    .into_iter()
+                    .map(|x| self.resolve(x, visiting))
+                    .collect(),
+            },
+            other => other,
+        }
+    }
+}
+
+/// Describes an enum that was reached while tracing.
+struct TracedEnum {
+    /// The name of the enum.
+    name: &'static str,
+    /// The enum variant that was being traced when this enum was first reached.
+    parent: Option<(usize, usize)>,
+    /// The names of the variants, along with their payloads if they have been traced.
+    variants: Vec<(&'static str, Option<TypeSchema>)>,
+}
+
+impl TracedEnum {
+    /// Whether every variant of this enum has been traced.
+    fn explored(&self) -> bool {
+        self.variants.iter().all(|x| x.1.is_some())
+    }
+}
+
+/// A deserializer which records the shape of the type being deserialized,
+/// producing placeholder values along the way.
+struct Tracer<'a> {
+    /// Where the traced schema is written.
+    schema: &'a mut Option<TypeSchema>,
+    /// The enums discovered so far.
+    state: &'a RefCell<TraceState>,
+}
+
+impl<'a> Tracer<'a> {
+    /// Records a primitive type.
+    fn primitive(self, name: &str) {
+        *self.schema = Some(TypeSchema::Primitive {
+            name: name.to_string(),
+        });
+    }
+
+    /// Creates a tracer for a nested value, which writes its schema to `schema`.
+    fn nested<'b>(&'b self, schema: &'b mut Option<TypeSchema>) -> Tracer<'b> {
+        Tracer {
+            schema,
+            state: self.state,
+        }
+    }
+
+    /// Traces a fixed number of elements, returning their schemas.
+    fn elements<'de, V: Visitor<'de>>(
+        state: &RefCell<TraceState>,
+        len: usize,
+        visitor: V,
+    ) -> Result<(V::Value, Vec<TypeSchema>), Error> {
+        let mut elements = Vec::with_capacity(len);
+        let value = visitor.visit_seq(TraceSeq {
+            elements: &mut elements,
+            remaining: len,
+            state,
+        })?;
+        Ok((value, elements))
+    }
+}
+
+/// Implements a deserialization method for a primitive type.
+macro_rules! trace_primitive {
+    ($method: ident, $visit: ident, $name: literal, $value: expr) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            self.primitive($name);
+            visitor.$visit($value)
+        }
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for Tracer<'a> {
+    type Error = Error;
+
+    trace_primitive!(deserialize_bool, visit_bool, "bool", false);
+    trace_primitive!(deserialize_i8, visit_i8, "i8", 0);
+    trace_primitive!(deserialize_i16, visit_i16, "i16", 0);
+    trace_primitive!(deserialize_i32, visit_i32, "i32", 0);
+    trace_primitive!(deserialize_i64, visit_i64, "i64", 0);
+    trace_primitive!(deserialize_i128, visit_i128, "i128", 0);
+    trace_primitive!(deserialize_u8, visit_u8, "u8", 0);
+    trace_primitive!(deserialize_u16, visit_u16, "u16", 0);
+    trace_primitive!(deserialize_u32, visit_u32, "u32", 0);
+    trace_primitive!(deserialize_u64, visit_u64, "u64", 0);
+    trace_primitive!(deserialize_u128, visit_u128, "u128", 0);
+    trace_primitive!(deserialize_f32, visit_f32, "f32", 0.0);
+    trace_primitive!(deserialize_f64, visit_f64, "f64", 0.0);
+    trace_primitive!(deserialize_char, visit_char, "char", '\0');
+    trace_primitive!(deserialize_str, visit_str, "str", "");
+    trace_primitive!(deserialize_string, visit_string, "str", String::new());
+    trace_primitive!(deserialize_bytes, visit_bytes, "bytes", &[]);
+    trace_primitive!(deserialize_byte_buf, visit_byte_buf, "bytes", Vec::new());
+    trace_primitive!(deserialize_identifier, visit_str, "str", "");
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+        Err(de::Error::custom("self-describing types cannot be traced"))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        *self.schema = Some(TypeSchema::Unit);
+        visitor.visit_unit()
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let mut inner = None;
+        let value = visitor.visit_some(self.nested(&mut inner))?;
+        *self.schema = Some(TypeSchema::Option {
+            inner: Box::new(inner.unwrap_or(TypeSchema::Unit)),
+        });
+        Ok(value)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        *self.schema = Some(TypeSchema::Unit);
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        *self.schema = Some(TypeSchema::Tuple {
+            name: name.to_string(),
+            elements: Vec::new(),
+        });
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let mut inner = None;
+        let value = visitor.visit_newtype_struct(self.nested(&mut inner))?;
+        *self.schema = Some(TypeSchema::Newtype {
+            name: name.to_string(),
+            inner: Box::new(inner.unwrap_or(TypeSchema::Unit)),
+        });
+        Ok(value)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let (value, mut elements) = Self::elements(self.state, 1, visitor)?;
+        *self.schema = Some(TypeSchema::Sequence {
+            element: Box::new(elements.pop().unwrap_or(TypeSchema::Unit)),
+        });
+        Ok(value)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_tuple_struct("", len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let (value, elements) = Self::elements(self.state, len, visitor)?;
+        *self.schema = Some(TypeSchema::Tuple {
+            name: name.to_string(),
+            elements,
+        });
+        Ok(value)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let mut key = None;
+        let mut value = None;
+        let result = visitor.visit_map(TraceMap {
+            key: &mut key,
+            value: &mut value,
+            remaining: true,
+            state: self.state,
+        })?;
+        *self.schema = Some(TypeSchema::Map {
+            key: Box::new(key.unwrap_or(TypeSchema::Unit)),
+            value: Box::new(value.unwrap_or(TypeSchema::Unit)),
+        });
+        Ok(result)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let (value, elements) = Self::elements(self.state, fields.len(), visitor)?;
+        *self.schema = Some(TypeSchema::Struct {
+            name: name.to_string(),
+            fields: fields.iter().map(|x| x.to_string()).zip(elements).collect(),
+        });
+        Ok(value)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        if variants.is_empty() {
+            return Err(de::Error::custom("enums without variants cannot be traced"));
+        }
+
+        let (index, variant) = {
+            let mut state = self.state.borrow_mut();
+            let index = match state.enums.iter().position(|x| x.name == name) {
+                Some(index) => index,
+                None => {
+                    let parent = state.stack.last().copied();
+                    state.enums.push(TracedEnum {
+                        name,
+                        parent,
+                        variants: variants.iter().map(|x| (*x, None)).collect(),
+                    });
+                    state.enums.len() - 1
+                }
+            };
+
+            let variant = state.choose_variant(index);
+            state.stack.push((index, variant));
+            (index, variant)
+        };
+
+        let mut payload = None;
+        let value = visitor.visit_enum(TraceEnum {
+            name: variants[variant],
+            payload: &mut payload,
+            state: self.state,
+            variant,
+        });
+
+        let mut state = self.state.borrow_mut();
+        state.stack.pop();
+        state.enums[index].variants[variant].1 = Some(payload.unwrap_or(TypeSchema::Unit));
+
+        // The variants are filled in once tracing completes.
+        *self.schema = Some(TypeSchema::Enum {
+            name: name.to_string(),
+            variants: Vec::new(),
+        });
+        value
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Traces each element of a sequence, tuple, or struct.
+struct TraceSeq<'a> {
+    /// The schemas of the elements traced so far.
+    elements: &'a mut Vec<TypeSchema>,
+    /// The number of elements left to produce.
+    remaining: usize,
+    /// The enums discovered so far.
+    state: &'a RefCell<TraceState>,
+}
+
+impl<'de, 'a> SeqAccess<'de> for TraceSeq<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        let mut schema = None;
+        let value = seed.deserialize(Tracer {
+            schema: &mut schema,
+            state: self.state,
+        })?;
+        self.elements.push(schema.unwrap_or(TypeSchema::Unit));
+        Ok(Some(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Traces the key and value types of a map containing a single entry.
+struct TraceMap<'a> {
+    /// The schema of the keys.
+    key: &'a mut Option<TypeSchema>,
+    /// The schema of the values.
+    value: &'a mut Option<TypeSchema>,
+    /// Whether the entry has yet to be produced.
+    remaining: bool,
+    /// The enums discovered so far.
+    state: &'a RefCell<TraceState>,
+}
+
+impl<'de, 'a> MapAccess<'de> for TraceMap<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        if !std::mem::take(&mut self.remaining) {
+            return Ok(None);
+        }
+
+        seed.deserialize(Tracer {
+            schema: &mut *self.key,
+            state: self.state,
+        })
+        .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(Tracer {
+            schema: &mut *self.value,
+            state: self.state,
+        })
+    }
+}
+
+/// Selects a single variant of an enum, and records the schema of its payload.
+struct TraceEnum<'a> {
+    /// The name of the selected variant.
+    name: &'static str,
+    /// Where the schema of the variant's payload is written.
+    payload: &'a mut Option<TypeSchema>,
+    /// The enums discovered so far.
+    state: &'a RefCell<TraceState>,
+    /// The index of the selected variant.
+    variant: usize,
+}
+
+impl<'de, 'a> EnumAccess<'de> for TraceEnum<'a> {
+    type Error = Error;
+
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Error> {
+        let variant = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(
+            self.variant as u32,
+        ))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, 'a> VariantAccess<'de> for TraceEnum<'a> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        *self.payload = Some(TypeSchema::Unit);
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        let mut inner = None;
+        let value = seed.deserialize(Tracer {
+            schema: &mut inner,
+            state: self.state,
+        })?;
+        *self.payload = Some(TypeSchema::Newtype {
+            name: self.name.to_string(),
+            inner: Box::new(inner.unwrap_or(TypeSchema::Unit)),
+        });
+        Ok(value)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        let (value, elements) = Tracer::elements(self.state, len, visitor)?;
+        *self.payload = Some(TypeSchema::Tuple {
+            name: self.name.to_string(),
+            elements,
+        });
+        Ok(value)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let (value, elements) = Tracer::elements(self.state, fields.len(), visitor)?;
+        *self.payload = Some(TypeSchema::Struct {
+            name: self.name.to_string(),
+            fields: fields.iter().map(|x| x.to_string()).zip(elements).collect(),
+        });
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wings::*;
+
+    /// A system type that the test actions are associated with.
+    #[derive(Clone, Debug)]
+    #[export_type]
+    struct TestSystem;
+
+    /// Serializes a value and reads it back, after checking that
+    /// the schema of its type is compatible with itself.
+    fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+        let schema = TypeSchema::of::<T>();
+        assert_eq!(schema.check_compatible(&schema), Ok(()));
+
+        let bytes = bincode::serialize(value).expect("Failed to serialize value");
+        let result = bincode::deserialize(&bytes).expect("Failed to deserialize value");
+        assert_eq!(bincode::serialize(&result).ok(), Some(bytes));
+        result
+    }
+
+    /// Finds the payload of the enum variant with the given name.
+    fn variant<'a>(schema: &'a TypeSchema, variant: &str) -> &'a TypeSchema {
+        let TypeSchema::Enum { variants, .. } = schema else {
+            panic!("Expected an enum, found {schema}");
+        };
+
+        variants
+            .iter()
+            .find_map(|(name, payload)| (name == variant).then_some(payload))
+            .unwrap_or_else(|| panic!("Variant {variant} not found in {schema}"))
+    }
+
+    #[test]
+    fn action_descriptor_round_trip() {
+        let analog = ActionDescriptor::<Analog>::new(
+            ActionName::new::<TestSystem>("look"),
+            "Turns the camera.",
+            &[AnalogBinding {
+                invert: true,
+                raw_input: RawInput::GamepadAxis(GamepadAxis::LeftStickX),
+            }],
+        );
+        assert_eq!(round_trip(&analog), analog);
+
+        let digital = ActionDescriptor::<Digital>::new(
+            ActionName::new::<TestSystem>("jump"),
+            "Leaves the ground.",
+            &[
+                DigitalBinding {
+                    threshold: 0.5,
+                    raw_input: RawInput::Key(Key::Space),
+                    trigger: TriggerMode::OnPress,
+                },
+                DigitalBinding {
+                    threshold: 0.5,
+                    raw_input: RawInput::GamepadButton(GamepadButton::South),
+                    trigger: TriggerMode::default(),
+                },
+            ],
+        );
+        assert_eq!(round_trip(&digital), digital);
+    }
+
+    #[test]
+    fn asset_round_trip() {
+        let Asset::Image { data, format } = round_trip(&Asset::Image {
+            data: vec![1, 2, 3],
+            format: ImageFormat::Png,
+        }) else {
+            panic!("Expected an image");
+        };
+        assert_eq!(data, [1, 2, 3]);
+        assert!(matches!(format, ImageFormat::Png));
+
+        let Asset::Text { value } = round_trip(&Asset::Text {
+            value: "voxel".to_string(),
+        }) else {
+            panic!("Expected text");
+        };
+        assert_eq!(value, "voxel");
+
+        let Asset::Binary { data } = round_trip(&Asset::Binary { data: vec![4, 5] }) else {
+            panic!("Expected binary data");
+        };
+        assert_eq!(data, [4, 5]);
+    }
+
+    #[test]
+    fn raycast_hit_round_trip() {
+        for object in [RaycastObject::Entity { id: 7 }, RaycastObject::World {}] {
+            let hit = round_trip(&RaycastHit {
+                distance: 2.5,
+                face: Direction::UP,
+                object,
+                voxel: IVec3::new(1, -2, 3),
+            });
+
+            assert_eq!(hit.distance, 2.5);
+            assert_eq!(hit.face, Direction::UP);
+            assert_eq!(hit.object, object);
+            assert_eq!(hit.voxel, IVec3::new(1, -2, 3));
+        }
+    }
+
+    #[test]
+    fn transform_round_trip() {
+        let transform = Transform {
+            position: WorldVec::from(Vec3A::new(1.0, 2.0, -3.0)),
+            rotation: Quat::from_rotation_y(1.0),
+        };
+        assert_eq!(round_trip(&transform), transform);
+    }
+
+    #[test]
+    fn enum_schema_includes_variant_payloads() {
+        let asset = TypeSchema::of::<Asset>();
+        let TypeSchema::Struct { name, fields } = variant(&asset, "Image") else {
+            panic!("Expected a struct variant");
+        };
+        assert_eq!(name, "Image");
+        assert_eq!(fields[0].0, "data");
+        assert!(
+            matches!(&fields[1], (field, TypeSchema::Enum { variants, .. })
+            if field == "format" && variants.len() == 2)
+        );
+
+        let hit = TypeSchema::of::<RaycastHit>();
+        let TypeSchema::Struct { fields, .. } = &hit else {
+            panic!("Expected a struct");
+        };
+        let object = &fields[2].1;
+        assert!(
+            matches!(variant(object, "Entity"), TypeSchema::Struct { fields, .. }
+            if fields.len() == 1)
+        );
+        assert!(
+            matches!(variant(object, "World"), TypeSchema::Struct { fields, .. }
+            if fields.is_empty())
+        );
+    }
+
+    #[test]
+    fn changed_variant_field_is_incompatible() {
+        #[allow(dead_code)]
+        #[derive(Deserialize)]
+        #[serde(rename = "Shape")]
+        enum OldShape {
+            Circle { radius: f32 },
+            Square { side: f32 },
+        }
+
+        #[allow(dead_code)]
+        #[derive(Deserialize)]
+        #[serde(rename = "Shape")]
+        enum NewShape {
+            Circle { radius: f64 },
+            Square { side: f32 },
+        }
+
+        #[allow(dead_code)]
+        #[derive(Deserialize)]
+        #[serde(rename = "Shape")]
+        enum RenamedShape {
+            Circle { radius: f32 },
+            Square { length: f32 },
+        }
+
+        let old = TypeSchema::of::<OldShape>();
+        assert_eq!(old.check_compatible(&old), Ok(()));
+
+        let mismatch = old
+            .check_compatible(&TypeSchema::of::<NewShape>())
+            .expect_err("Changing a variant's field type should be incompatible");
+        assert!(mismatch.path.starts_with("Shape::Circle"));
+
+        let mismatch = old
+            .check_compatible(&TypeSchema::of::<RenamedShape>())
+            .expect_err("Renaming a variant's field should be incompatible");
+        assert!(mismatch.path.starts_with("Shape::Square"));
+    }
+
+    #[test]
+    fn marshaled_types_are_self_compatible() {
+        for schema in TypeSchema::marshaled_types() {
+            assert_eq!(schema.check_compatible(&schema), Ok(()));
+        }
+    }
+
+    #[test]
+    fn recursive_types_terminate() {
+        let schema = TypeSchema::of::<TypeSchema>();
+        assert!(
+            matches!(variant(&schema, "Option"), TypeSchema::Struct { fields, .. }
+            if fields.len() == 1)
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use std::cell::*;
+use std::mem::*;
+use std::rc::*;
+
+/// A single-threaded queue for deferring work to a later point in the main loop.
+/// Cloning the queue produces another handle to the same underlying storage,
+/// so items may be pushed from many places and then processed together.
+///
+/// This type is the supported alternative to `std::sync::mpsc` channels, since
+/// WASM plugins run on a single thread. It is intentionally neither `Send` nor `Sync`.
+#[derive(Debug)]
+pub struct EventQueue<T>(Rc<RefCell<Vec<T>>>);
+
+impl<T> EventQueue<T> {
+    /// Creates a new, empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all items from the queue, in the order that they were pushed.
+    /// This should be called once per frame from an event handler. Items that
+    /// are pushed while the returned iterator is being consumed will remain in
+    /// the queue until the next call.
+    pub fn drain_each_frame(&self) -> std::vec::IntoIter<T> {
+        take(&mut *self.0.borrow_mut()).into_iter()
+    }
+
+    /// Whether the queue currently contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    /// The number of items currently in the queue.
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Adds an item to the back of the queue.
+    pub fn push(&self, item: T) {
+        self.0.borrow_mut().push(item);
+    }
+}
+
+impl<T> Clone for EventQueue<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Default for EventQueue<T> {
+    fn default() -> Self {
+        Self(Rc::default())
+    }
+}
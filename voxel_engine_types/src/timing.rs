@@ -1,23 +1,74 @@
+use crate::math::*;
 use std::time::*;
 use wings::*;
 
+/// Provides access to a high-resolution clock. Available on both the [`Client`](crate::Client)
+/// and [`Server`](crate::Server).
+#[system_trait(host)]
+pub trait Clock: 'static {
+    /// Returns the amount of real time that has passed since the engine started. Unlike the
+    /// values reported by [`FrameTiming`] and [`TickTiming`], this advances continuously during a
+    /// frame, so it may be used to measure how long some work took. The value is monotonic, and
+    /// is unaffected by the time scale.
+    #[global(global_now)]
+    fn now(&self) -> Duration;
+}
+
 /// Provides data about frame timings. Only available on the [`Client`](crate::Client).
+///
+/// Frame timings are measured in game time, which advances at the rate given by
+/// [`Self::time_scale`]. Gameplay systems should use these values so that they
+/// respect pausing and slow motion. UI and other systems which must keep running while
+/// the game is paused should use [`Self::unscaled_frame_duration`] instead.
 #[system_trait(host)]
 pub trait FrameTiming: 'static {
+    /// Returns the number of seconds since this timer was started, at double precision.
+    /// This value is monotonic, and remains precise over long-running sessions.
+    fn elapsed_secs_f64(&self) -> f64;
+
     /// Returns the number of frames since this timer was started.
     fn frame_count(&self) -> u64;
 
-    /// Returns the time that the last frame took.
+    /// Returns the time that the last frame took, multiplied by the time scale.
+    /// This is zero while the game is paused.
     fn frame_duration(&self) -> Duration;
 
+    /// Returns whether this timer is currently replaying recorded frame durations.
+    fn is_playback(&self) -> bool;
+
     /// Returns the time that the previous frame ended, relative to when
     /// this timer was started.
     fn last_frame(&self) -> Duration;
+
+    /// Replays recorded frame durations for deterministic playback. Beginning with the next
+    /// frame, each frame reports the next duration from the list instead of the wall-clock
+    /// time, and all other values on this timer advance accordingly. Once every duration has
+    /// been used, or if the list is empty, the timer returns to measuring real time.
+    fn set_playback(&mut self, durations: Vec<Duration>);
+
+    /// Sets the rate at which game time advances, beginning with the next frame. A scale of
+    /// `1.0` is real time, `0.5` is half speed, and `0.0` pauses the game. Negative scales are
+    /// treated as zero.
+    fn set_time_scale(&mut self, scale: f32);
+
+    /// Gets the rate at which game time advances. See [`Self::set_time_scale`].
+    fn time_scale(&self) -> f32;
+
+    /// Returns the real time that the last frame took, ignoring the time scale.
+    fn unscaled_frame_duration(&self) -> Duration;
 }
 
 /// Provides data about tick-based timings. Only available on the [`Server`](crate::Server).
+///
+/// Ticks are scheduled in game time. Each tick represents a fixed [`Self::interval`] of game
+/// time, so a lower [`Self::time_scale`] causes ticks to occur less often in real time,
+/// and no ticks occur while the game is paused.
 #[system_trait(host)]
 pub trait TickTiming: 'static {
+    /// Returns the number of seconds since this timer was started, at double precision.
+    /// This value is monotonic, and remains precise over long-running sessions.
+    fn elapsed_secs_f64(&self) -> f64;
+
     /// Provides the interval at which this timer ticks.
     fn interval(&self) -> Duration;
 
@@ -29,9 +80,145 @@ pub trait TickTiming: 'static {
     /// this timer was started.
     fn next_tick(&self) -> Duration;
 
+    /// Sets the rate at which game time advances, beginning with the next tick.
+    /// See [`FrameTiming::set_time_scale`] for details.
+    fn set_time_scale(&mut self, scale: f32);
+
     /// Retrieves the number of ticks that have occurred since this
     /// tick timer was started.
     fn tick_count(&self) -> u64;
+
+    /// Returns the number of ticks that are overdue, beyond the one currently being
+    /// processed. This is zero when the timer is keeping up with its schedule.
+    fn ticks_behind(&self) -> u32;
+
+    /// Gets the rate at which game time advances. See [`Self::set_time_scale`].
+    fn time_scale(&self) -> f32;
+}
+
+/// Spreads expensive work across multiple frames, by performing only as much
+/// as fits within a fixed amount of time each frame.
+#[derive(Copy, Clone, Debug)]
+pub struct Budget {
+    /// Whether all work was completed during the last call to [`Self::run`].
+    finished: bool,
+    /// The maximum amount of time to spend on work during each call to [`Self::run`].
+    per_frame: Duration,
+}
+
+impl Budget {
+    /// Creates a new budget which spends at most `per_frame` on work each time that it is run.
+    pub fn new(per_frame: Duration) -> Self {
+        Self {
+            finished: false,
+            per_frame,
+        }
+    }
+
+    /// Whether all work was completed during the last call to [`Self::run`].
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Repeatedly calls `work`, which should perform a small unit of work and return whether
+    /// more remains, until either it returns `false` or the budget is exhausted. This should
+    /// be called once per frame. `work` is always called at least once, so progress is made
+    /// even if a single unit takes longer than the budget. Time is measured with [`Clock`].
+    pub fn run<F: FnMut() -> bool>(&mut self, mut work: F) {
+        let start = global_now();
+        loop {
+            if !work() {
+                self.finished = true;
+                return;
+            }
+
+            if self.per_frame <= global_now().saturating_sub(start) {
+                self.finished = false;
+                return;
+            }
+        }
+    }
+}
+
+/// Animates a value between two endpoints over a fixed duration.
+#[derive(Copy, Clone, Debug)]
+pub struct Tween<T: Lerp> {
+    /// The function that maps linear progress to eased progress.
+    easing: fn(f32) -> f32,
+    /// The amount of time that has passed since the tween started.
+    elapsed: Duration,
+    /// The total length of the tween.
+    duration: Duration,
+    /// The value at the end of the tween.
+    end: T,
+    /// The value at the beginning of the tween.
+    start: T,
+}
+
+impl<T: Lerp> Tween<T> {
+    /// Creates a new tween between `start` and `end` that lasts for `duration`.
+    /// The `easing` function maps linear progress on the range `[0, 1]` to
+    /// an interpolation parameter; see the [`easing`] module for common choices.
+    pub fn new(start: T, end: T, duration: Duration, easing: fn(f32) -> f32) -> Self {
+        Self {
+            easing,
+            elapsed: Duration::ZERO,
+            duration,
+            end,
+            start,
+        }
+    }
+
+    /// Whether the tween has reached its end value.
+    pub fn finished(&self) -> bool {
+        self.duration <= self.elapsed
+    }
+
+    /// Advances the tween by `dt`, and returns the new current value.
+    pub fn update(&mut self, dt: Duration) -> T {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+        self.value()
+    }
+
+    /// Gets the current value of the tween.
+    pub fn value(&self) -> T {
+        let progress = if self.duration.is_zero() {
+            1.0
+        } else {
+            self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        };
+
+        self.start.lerp(&self.end, (self.easing)(progress))
+    }
+}
+
+/// Common easing functions for use with [`Tween`]. Each function maps progress
+/// on the range `[0, 1]` to an interpolation parameter, where `0` maps to `0`
+/// and `1` maps to `1`.
+pub mod easing {
+    /// Progresses at a constant rate.
+    pub fn linear(t: f32) -> f32 {
+        t
+    }
+
+    /// Starts slowly and accelerates toward the end.
+    pub fn ease_in(t: f32) -> f32 {
+        t * t * t
+    }
+
+    /// Starts quickly and decelerates toward the end.
+    pub fn ease_out(t: f32) -> f32 {
+        1.0 - ease_in(1.0 - t)
+    }
+
+    /// Starts slowly, accelerates through the middle, and decelerates toward the end.
+    pub fn ease_in_out(t: f32) -> f32 {
+        if t < 0.5 {
+            4.0 * t * t * t
+        } else {
+            1.0 - 4.0 * (1.0 - t) * (1.0 - t) * (1.0 - t)
+        }
+    }
 }
 
 /// The set of events that this module raises.
@@ -39,12 +226,76 @@ pub mod on {
     use super::*;
 
     /// Raised whenever a new frame occurs.
+    ///
+    /// Each frame proceeds in the following order:
+    ///
+    /// 1. User input is polled, so every event below observes the same input state.
+    /// 2. [`PreFrame`] is raised.
+    /// 3. [`Frame`] is raised.
+    /// 4. The engine renders the world.
+    /// 5. [`PostFrame`] is raised.
+    /// 6. `egui` output from all of the above events is drawn on top of the world.
     #[derive(Clone, Debug, Default)]
     #[export_type]
     pub struct Frame;
 
+    /// Raised at the start of every frame, after input has been polled
+    /// but before [`Frame`]. See [`Frame`] for the full ordering.
+    #[derive(Clone, Debug, Default)]
+    #[export_type]
+    pub struct PreFrame;
+
+    /// Raised at the end of every frame, after the engine has rendered the world.
+    /// See [`Frame`] for the full ordering.
+    #[derive(Clone, Debug, Default)]
+    #[export_type]
+    pub struct PostFrame;
+
     /// Raised whenever a new tick occurs.
     #[derive(Clone, Debug, Default)]
     #[export_type]
     pub struct Tick;
+
+    /// Raised before a tick whenever the tick timer has fallen behind schedule.
+    #[derive(Clone, Debug, Default)]
+    #[export_type]
+    pub struct TickLag {
+        /// The number of ticks that are overdue.
+        pub behind: u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether every component of two colors is within a small tolerance.
+    fn colors_close(a: Rgba, b: Rgba) -> bool {
+        [a.r - b.r, a.g - b.g, a.b - b.b, a.a - b.a]
+            .iter()
+            .all(|x| x.abs() < 1e-4)
+    }
+
+    #[test]
+    fn color_tween() {
+        let mut tween = Tween::new(
+            Rgba::BLACK,
+            Rgba::WHITE,
+            Duration::from_secs(1),
+            easing::linear,
+        );
+        assert!(colors_close(tween.value(), Rgba::BLACK));
+
+        let middle = tween.update(Duration::from_millis(500));
+        assert!(0.0 < middle.r && middle.r < 1.0);
+        assert!((middle.r - middle.g).abs() < 1e-4 && (middle.g - middle.b).abs() < 1e-4);
+        assert_eq!(middle.a, 1.0);
+        assert!(!tween.finished());
+
+        assert!(colors_close(
+            tween.update(Duration::from_millis(500)),
+            Rgba::WHITE
+        ));
+        assert!(tween.finished());
+    }
 }
@@ -0,0 +1,34 @@
+use crate::math::*;
+use wings::*;
+
+/// Allows for editing the voxels of the main world grid.
+/// Only available on the [`Server`](crate::Server).
+#[system_trait(host)]
+pub trait World: 'static {
+    /// Deletes some voxels at the given world position.
+    /// The edit occurs at the end of the current tick.
+    fn delete_voxels_at(&self, position: IVec3);
+
+    /// Places some voxels at the given world position. The voxel shape
+    /// changes based upon index.
+    /// The edit occurs at the end of the current tick.
+    fn place_voxels_at(&self, position: IVec3, shape_index: u32);
+}
+
+/// The set of events that this module raises.
+pub mod on {
+    use super::*;
+
+    /// Raised on the server whenever the engine finishes generating the terrain
+    /// for a chunk. Edits issued via [`World`] while handling this event are
+    /// applied before the chunk is sent to any client, and before any player
+    /// edits to the chunk are processed.
+    #[derive(Clone, Debug, Default)]
+    #[export_type]
+    pub struct ChunkGenerated {
+        /// The minimum voxel coordinate of the chunk.
+        pub origin: IVec3,
+        /// The size of the chunk, in voxels.
+        pub size: IVec3,
+    }
+}